@@ -0,0 +1,185 @@
+// This file is part of pezkuwi-sdk.
+
+// Copyright (C) Pezkuwi Foundation. and Kurdistan Blockchain Technologies Institute (KBTI) 2024.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Setup code for [`super::command`] which would otherwise bloat that module.
+//!
+//! Should only be used for benchmarking as it may break in other contexts.
+
+use crate::service::FullClient;
+use pez_minimal_template_runtime::{
+	interface::{AccountId, Balance, Nonce},
+	Runtime, RuntimeCall, TxExtension, VERSION,
+};
+use pezkuwi_sdk::{
+	pezframe_benchmarking_cli::ExtrinsicBuilder,
+	pezkuwi_sdk_frame::runtime::prelude::ExtrinsicFor,
+	pezsc_cli::Result,
+	pezsp_blockchain::HeaderBackend,
+	pezsp_core::{Encode, Get, Pair},
+	pezsp_inherents::{InherentData, InherentDataProvider},
+	pezsp_keyring::Sr25519Keyring,
+	pezsp_runtime::{generic, OpaqueExtrinsic, SaturatedConversion},
+	*,
+};
+use std::{sync::Arc, time::Duration};
+
+/// Generates extrinsics for the `benchmark overhead` command.
+///
+/// Note: Should only be used for benchmarking.
+pub struct RemarkBuilder {
+	client: Arc<FullClient>,
+}
+
+impl RemarkBuilder {
+	/// Creates a new [`Self`] from the given client.
+	pub fn new(client: Arc<FullClient>) -> Self {
+		Self { client }
+	}
+}
+
+impl ExtrinsicBuilder for RemarkBuilder {
+	fn pallet(&self) -> &str {
+		"system"
+	}
+
+	fn extrinsic(&self) -> &str {
+		"remark"
+	}
+
+	fn build(&self, nonce: u32) -> std::result::Result<OpaqueExtrinsic, &'static str> {
+		let acc = Sr25519Keyring::Bob.pair();
+		let extrinsic = create_benchmark_extrinsic(
+			self.client.as_ref(),
+			acc,
+			pezframe_system::Call::remark { remark: vec![] }.into(),
+			nonce,
+		);
+
+		Ok(extrinsic.into())
+	}
+}
+
+/// Generates `Balances::TransferKeepAlive` extrinsics for the benchmarks.
+///
+/// Note: Should only be used for benchmarking.
+pub struct TransferKeepAliveBuilder {
+	client: Arc<FullClient>,
+	dest: AccountId,
+	value: Balance,
+}
+
+impl TransferKeepAliveBuilder {
+	/// Creates a new [`Self`] from the given client.
+	pub fn new(client: Arc<FullClient>, dest: AccountId, value: Balance) -> Self {
+		Self { client, dest, value }
+	}
+}
+
+impl ExtrinsicBuilder for TransferKeepAliveBuilder {
+	fn pallet(&self) -> &str {
+		"balances"
+	}
+
+	fn extrinsic(&self) -> &str {
+		"transfer_keep_alive"
+	}
+
+	fn build(&self, nonce: u32) -> std::result::Result<OpaqueExtrinsic, &'static str> {
+		let acc = Sr25519Keyring::Bob.pair();
+		let extrinsic = create_benchmark_extrinsic(
+			self.client.as_ref(),
+			acc,
+			pezpallet_balances::Call::transfer_keep_alive {
+				dest: self.dest.clone().into(),
+				value: self.value,
+			}
+			.into(),
+			nonce,
+		);
+
+		Ok(extrinsic.into())
+	}
+}
+
+/// Create a transaction using the given `call`.
+///
+/// Note: Should only be used for benchmarking.
+pub fn create_benchmark_extrinsic(
+	client: &FullClient,
+	sender: pezsp_core::sr25519::Pair,
+	call: RuntimeCall,
+	nonce: Nonce,
+) -> ExtrinsicFor<Runtime> {
+	let genesis_hash = client.block_hash(0).ok().flatten().expect("Genesis block exists; qed");
+	let best_hash = client.chain_info().best_hash;
+	let best_block = client.chain_info().best_number;
+
+	let period = <Runtime as pezframe_system::Config>::BlockHashCount::get()
+		.checked_next_power_of_two()
+		.map(|c| c / 2)
+		.unwrap_or(2) as u64;
+	let tx_ext: TxExtension = (
+		pezframe_system::CheckNonZeroSender::new(),
+		pezframe_system::CheckSpecVersion::new(),
+		pezframe_system::CheckTxVersion::new(),
+		pezframe_system::CheckGenesis::new(),
+		pezframe_system::CheckEra::from(generic::Era::mortal(period, best_block.saturated_into())),
+		pezframe_system::CheckNonce::from(nonce),
+		pezframe_system::CheckWeight::new(),
+		pezpallet_asset_tx_payment::ChargeAssetTxPayment::from(0, None),
+		pezframe_system::WeightReclaim::new(),
+	);
+
+	let raw_payload = generic::SignedPayload::from_raw(
+		call.clone(),
+		tx_ext.clone(),
+		(
+			(),
+			VERSION.spec_version,
+			VERSION.transaction_version,
+			genesis_hash,
+			best_hash,
+			(),
+			(),
+			(),
+			(),
+		),
+	);
+	let signature = raw_payload.using_encoded(|e| sender.sign(e));
+
+	ExtrinsicFor::<Runtime>::new_signed(
+		call,
+		pezsp_runtime::AccountId32::from(sender.public()).into(),
+		signature.into(),
+		tx_ext,
+	)
+}
+
+/// Generates inherent data for the `benchmark overhead` command.
+///
+/// Note: Should only be used for benchmarking.
+pub fn inherent_benchmark_data() -> Result<InherentData> {
+	let mut inherent_data = InherentData::new();
+	// The benchmark blocks carry no Aura pre-digest, so the timestamp has to stay in slot 0 to
+	// match `CurrentSlot` at genesis.
+	let d = Duration::from_millis(0);
+	let timestamp = pezsp_timestamp::InherentDataProvider::new(d.into());
+
+	futures::executor::block_on(timestamp.provide_inherent_data(&mut inherent_data))
+		.map_err(|e| format!("creating inherent data: {e:?}"))?;
+	Ok(inherent_data)
+}
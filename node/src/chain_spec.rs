@@ -15,7 +15,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use pez_minimal_template_runtime::WASM_BINARY;
+use pez_minimal_template_runtime::{
+	genesis_config_presets::STAGING_RUNTIME_PRESET, SS58_PREFIX, WASM_BINARY,
+};
 use pezkuwi_sdk::{
 	pezsc_service::{ChainType, Properties},
 	*,
@@ -26,8 +28,9 @@ pub type ChainSpec = pezsc_service::GenericChainSpec;
 
 fn props() -> Properties {
 	let mut properties = Properties::new();
-	properties.insert("tokenDecimals".to_string(), 0.into());
+	properties.insert("tokenDecimals".to_string(), 12.into());
 	properties.insert("tokenSymbol".to_string(), "PEZ".into());
+	properties.insert("ss58Format".to_string(), SS58_PREFIX.into());
 	properties
 }
 
@@ -40,3 +43,23 @@ pub fn development_chain_spec() -> Result<ChainSpec, String> {
 		.with_properties(props())
 		.build())
 }
+
+pub fn local_chain_spec() -> Result<ChainSpec, String> {
+	Ok(ChainSpec::builder(WASM_BINARY.expect("Local wasm not available"), Default::default())
+		.with_name("Local Testnet")
+		.with_id("local_testnet")
+		.with_chain_type(ChainType::Local)
+		.with_genesis_config_preset_name(pezsp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET)
+		.with_properties(props())
+		.build())
+}
+
+pub fn staging_chain_spec() -> Result<ChainSpec, String> {
+	Ok(ChainSpec::builder(WASM_BINARY.expect("Staging wasm not available"), Default::default())
+		.with_name("Staging")
+		.with_id("staging")
+		.with_chain_type(ChainType::Live)
+		.with_genesis_config_preset_name(STAGING_RUNTIME_PRESET)
+		.with_properties(props())
+		.build())
+}
@@ -18,8 +18,13 @@ use pezkuwi_sdk::{pezsc_cli::RunCmd, *};
 
 #[derive(Debug, Clone)]
 pub enum Consensus {
+	/// Seal a block every given number of milliseconds, and on request over RPC.
 	ManualSeal(u64),
+	/// Seal blocks only on request over RPC.
+	ManualSealOnDemand,
 	InstantSeal,
+	/// Aura block authoring with GRANDPA finality, for networks with several validators.
+	Aura,
 	None,
 }
 
@@ -29,8 +34,12 @@ impl std::str::FromStr for Consensus {
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
 		Ok(if s == "instant-seal" {
 			Consensus::InstantSeal
+		} else if s == "manual-seal" {
+			Consensus::ManualSealOnDemand
 		} else if let Some(block_time) = s.strip_prefix("manual-seal-") {
 			Consensus::ManualSeal(block_time.parse().map_err(|_| "invalid block time")?)
+		} else if s == "aura" {
+			Consensus::Aura
 		} else if s.to_lowercase() == "none" {
 			Consensus::None
 		} else {
@@ -47,10 +56,27 @@ pub struct Cli {
 	#[clap(long, default_value = "manual-seal-3000")]
 	pub consensus: Consensus,
 
+	/// Do not seal a block on the manual seal block time if there are no transactions to include.
+	///
+	/// Only valid with `--consensus manual-seal-<block time>`.
+	#[clap(long)]
+	pub skip_empty_blocks: bool,
+
 	#[clap(flatten)]
 	pub run: RunCmd,
 }
 
+impl Cli {
+	/// Reject flag combinations that `clap` can't, e.g. `--skip-empty-blocks` with a consensus
+	/// that doesn't seal on a block time.
+	pub fn validate(&self) -> Result<(), String> {
+		if self.skip_empty_blocks && !matches!(self.consensus, Consensus::ManualSeal(_)) {
+			return Err("--skip-empty-blocks requires --consensus manual-seal-<block time>".into());
+		}
+		Ok(())
+	}
+}
+
 #[derive(Debug, clap::Subcommand)]
 pub enum Subcommand {
 	/// Key management cli utilities
@@ -80,4 +106,8 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(pezsc_cli::ChainInfoCmd),
+
+	/// Sub-commands concerned with benchmarking.
+	#[command(subcommand)]
+	Benchmark(pezframe_benchmarking_cli::BenchmarkCmd),
 }
@@ -16,11 +16,21 @@
 // limitations under the License.
 
 use crate::{
+	benchmarking::{inherent_benchmark_data, RemarkBuilder, TransferKeepAliveBuilder},
 	chain_spec,
-	cli::{Cli, Subcommand},
+	cli::{Cli, Consensus, Subcommand},
 	service,
 };
-use pezkuwi_sdk::{pezsc_cli::BizinikiwiCli, pezsc_service::PartialComponents, *};
+use pez_minimal_template_runtime::interface::{MinimumBalance, OpaqueBlock};
+use pezkuwi_sdk::{
+	pezframe_benchmarking_cli::{BenchmarkCmd, ExtrinsicFactory, BIZINIKIWI_REFERENCE_HARDWARE},
+	pezsc_cli::BizinikiwiCli,
+	pezsc_service::PartialComponents,
+	pezsp_core::Get,
+	pezsp_keyring::Sr25519Keyring,
+	pezsp_runtime::traits::HashingFor,
+	*,
+};
 
 impl BizinikiwiCli for Cli {
 	fn impl_name() -> String {
@@ -50,6 +60,8 @@ impl BizinikiwiCli for Cli {
 	fn load_spec(&self, id: &str) -> Result<Box<dyn pezsc_service::ChainSpec>, String> {
 		Ok(match id {
 			"dev" => Box::new(chain_spec::development_chain_spec()?),
+			"local" => Box::new(chain_spec::local_chain_spec()?),
+			"staging" => Box::new(chain_spec::staging_chain_spec()?),
 			path =>
 				Box::new(chain_spec::ChainSpec::from_json_file(std::path::PathBuf::from(path))?),
 		})
@@ -59,6 +71,7 @@ impl BizinikiwiCli for Cli {
 /// Parse and run command line arguments
 pub fn run() -> pezsc_cli::Result<()> {
 	let cli = Cli::from_args();
+	cli.validate().map_err(pezsc_cli::Error::Input)?;
 
 	match &cli.subcommand {
 		Some(Subcommand::Key(cmd)) => cmd.run(&cli),
@@ -70,21 +83,23 @@ pub fn run() -> pezsc_cli::Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
 				let PartialComponents { client, task_manager, import_queue, .. } =
-					service::new_partial(&config)?;
+					service::new_partial(&config, &cli.consensus)?;
 				Ok((cmd.run(client, import_queue), task_manager))
 			})
 		},
 		Some(Subcommand::ExportBlocks(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
-				let PartialComponents { client, task_manager, .. } = service::new_partial(&config)?;
+				let PartialComponents { client, task_manager, .. } =
+					service::new_partial(&config, &cli.consensus)?;
 				Ok((cmd.run(client, config.database), task_manager))
 			})
 		},
 		Some(Subcommand::ExportState(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
-				let PartialComponents { client, task_manager, .. } = service::new_partial(&config)?;
+				let PartialComponents { client, task_manager, .. } =
+					service::new_partial(&config, &cli.consensus)?;
 				Ok((cmd.run(client, config.chain_spec), task_manager))
 			})
 		},
@@ -92,7 +107,7 @@ pub fn run() -> pezsc_cli::Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
 				let PartialComponents { client, task_manager, import_queue, .. } =
-					service::new_partial(&config)?;
+					service::new_partial(&config, &cli.consensus)?;
 				Ok((cmd.run(client, import_queue), task_manager))
 			})
 		},
@@ -104,27 +119,113 @@ pub fn run() -> pezsc_cli::Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
 				let PartialComponents { client, task_manager, backend, .. } =
-					service::new_partial(&config)?;
-				Ok((cmd.run(client, backend, None), task_manager))
+					service::new_partial(&config, &cli.consensus)?;
+				if matches!(cli.consensus, Consensus::Aura) {
+					// GRANDPA keeps authority set changes in aux storage, revert them as well.
+					let aux_revert = Box::new(|client, _, blocks| {
+						pezsc_consensus_grandpa::revert(client, blocks)?;
+						Ok(())
+					});
+					Ok((cmd.run(client, backend, Some(aux_revert)), task_manager))
+				} else {
+					Ok((cmd.run(client, backend, None), task_manager))
+				}
 			})
 		},
-		Some(Subcommand::ChainInfo(cmd)) => {
+		Some(Subcommand::Benchmark(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
+
 			runner.sync_run(|config| {
-				cmd.run::<pez_minimal_template_runtime::interface::OpaqueBlock>(&config)
+				// This switch needs to be in the client, since the client decides
+				// which sub-commands it wants to support.
+				match cmd {
+					BenchmarkCmd::Pallet(cmd) => {
+						if !cfg!(feature = "runtime-benchmarks") {
+							return Err(
+								"Runtime benchmarking wasn't enabled when building the node. \
+								You can enable it with `--features runtime-benchmarks`."
+									.into(),
+							);
+						}
+
+						cmd.run_with_spec::<HashingFor<OpaqueBlock>, ()>(Some(config.chain_spec))
+					},
+					BenchmarkCmd::Block(cmd) => {
+						let PartialComponents { client, .. } =
+							service::new_partial(&config, &cli.consensus)?;
+						cmd.run(client)
+					},
+					#[cfg(not(feature = "runtime-benchmarks"))]
+					BenchmarkCmd::Storage(_) => Err(
+						"Storage benchmarking can be enabled with `--features runtime-benchmarks`."
+							.into(),
+					),
+					#[cfg(feature = "runtime-benchmarks")]
+					BenchmarkCmd::Storage(cmd) => {
+						let PartialComponents { client, backend, .. } =
+							service::new_partial(&config, &cli.consensus)?;
+						let db = backend.expose_db();
+						let storage = backend.expose_storage();
+						let shared_cache = backend.expose_shared_trie_cache();
+
+						cmd.run(config, client, db, storage, shared_cache)
+					},
+					BenchmarkCmd::Overhead(cmd) => {
+						let PartialComponents { client, .. } =
+							service::new_partial(&config, &cli.consensus)?;
+						let ext_builder = RemarkBuilder::new(client.clone());
+
+						cmd.run(
+							config.chain_spec.name().into(),
+							client,
+							inherent_benchmark_data()?,
+							Vec::new(),
+							&ext_builder,
+							false,
+						)
+					},
+					BenchmarkCmd::Extrinsic(cmd) => {
+						let PartialComponents { client, .. } =
+							service::new_partial(&config, &cli.consensus)?;
+						// Register the *Remark* and *TKA* builders.
+						let ext_factory = ExtrinsicFactory(vec![
+							Box::new(RemarkBuilder::new(client.clone())),
+							Box::new(TransferKeepAliveBuilder::new(
+								client.clone(),
+								Sr25519Keyring::Alice.to_account_id(),
+								MinimumBalance::get(),
+							)),
+						]);
+
+						cmd.run(client, inherent_benchmark_data()?, Vec::new(), &ext_factory)
+					},
+					BenchmarkCmd::Machine(cmd) =>
+						cmd.run(&config, BIZINIKIWI_REFERENCE_HARDWARE.clone()),
+				}
 			})
 		},
+		Some(Subcommand::ChainInfo(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.sync_run(|config| cmd.run::<OpaqueBlock>(&config))
+		},
 		None => {
 			let runner = cli.create_runner(&cli.run)?;
 			runner.run_node_until_exit(|config| async move {
 				match config.network.network_backend.unwrap_or_default() {
 					pezsc_network::config::NetworkBackendType::Libp2p =>
-						service::new_full::<pezsc_network::NetworkWorker<_, _>>(config, cli.consensus)
-							.map_err(pezsc_cli::Error::Service),
-					pezsc_network::config::NetworkBackendType::Litep2p => service::new_full::<
-						pezsc_network::Litep2pNetworkBackend,
-					>(config, cli.consensus)
-					.map_err(pezsc_cli::Error::Service),
+						service::new_full::<pezsc_network::NetworkWorker<_, _>>(
+							config,
+							cli.consensus,
+							cli.skip_empty_blocks,
+						)
+						.map_err(pezsc_cli::Error::Service),
+					pezsc_network::config::NetworkBackendType::Litep2p =>
+						service::new_full::<pezsc_network::Litep2pNetworkBackend>(
+							config,
+							cli.consensus,
+							cli.skip_empty_blocks,
+						)
+						.map_err(pezsc_cli::Error::Service),
 				}
 			})
 		},
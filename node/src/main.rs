@@ -18,6 +18,7 @@
 //! Bizinikiwi Node Template CLI library.
 #![warn(missing_docs)]
 
+mod benchmarking;
 mod chain_spec;
 mod cli;
 mod command;
@@ -22,9 +22,11 @@
 
 #![warn(missing_docs)]
 
+use futures::channel::mpsc;
 use jsonrpsee::RpcModule;
-use pez_minimal_template_runtime::interface::{AccountId, Nonce, OpaqueBlock};
+use pez_minimal_template_runtime::interface::{AccountId, Balance, Hash, Nonce, OpaqueBlock};
 use pezkuwi_sdk::{
+	pezsc_consensus_manual_seal::EngineCommand,
 	pezsc_transaction_pool_api::TransactionPool,
 	pezsp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata},
 	*,
@@ -37,6 +39,8 @@ pub struct FullDeps<C, P> {
 	pub client: Arc<C>,
 	/// Transaction pool instance.
 	pub pool: Arc<P>,
+	/// Channel to the manual seal authorship task, if the node runs manual seal.
+	pub command_sink: Option<mpsc::Sender<EngineCommand<Hash>>>,
 }
 
 #[docify::export]
@@ -54,13 +58,25 @@ where
 		+ 'static,
 	C::Api: pezsp_block_builder::BlockBuilder<OpaqueBlock>,
 	C::Api: pezframe_rpc_system::AccountNonceApi<OpaqueBlock, AccountId, Nonce>,
+	C::Api: pezpallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<OpaqueBlock, Balance>,
 	P: TransactionPool + 'static,
 {
-	use pezkuwi_sdk::pezframe_rpc_system::{System, SystemApiServer};
+	use pezkuwi_sdk::{
+		pezframe_rpc_system::{System, SystemApiServer},
+		pezpallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer},
+		pezsc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApiServer},
+	};
 	let mut module = RpcModule::new(());
-	let FullDeps { client, pool } = deps;
+	let FullDeps { client, pool, command_sink } = deps;
 
 	module.merge(System::new(client.clone(), pool.clone()).into_rpc())?;
+	module.merge(TransactionPayment::new(client).into_rpc())?;
+
+	if let Some(command_sink) = command_sink {
+		// `engine_createBlock` and `engine_finalizeBlock` send their commands to the manual seal
+		// authorship task through this channel.
+		module.merge(ManualSeal::new(command_sink).into_rpc())?;
+	}
 
 	Ok(module)
 }
@@ -20,14 +20,22 @@ use futures::FutureExt;
 use pez_minimal_template_runtime::{interface::OpaqueBlock as Block, RuntimeApi};
 use pezkuwi_sdk::{
 	pezsc_client_api::backend::Backend,
+	pezsc_consensus_aura::{start_aura, ImportQueueParams, SlotProportion, StartAuraParams},
+	pezsc_consensus_grandpa::SharedVoterState,
+	pezsc_consensus_manual_seal::consensus::aura::AuraConsensusDataProvider,
 	pezsc_executor::WasmExecutor,
+	pezsc_network_sync::WarpSyncConfig,
 	pezsc_service::{error::Error as ServiceError, Configuration, TaskManager},
 	pezsc_telemetry::{Telemetry, TelemetryWorker},
-	pezsc_transaction_pool_api::OffchainTransactionPoolFactory,
+	pezsc_transaction_pool_api::{OffchainTransactionPoolFactory, TransactionPool},
+	pezsp_blockchain::HeaderBackend,
+	pezsp_consensus_aura::sr25519::{
+		AuthorityPair as AuraPair, AuthoritySignature as AuraSignature,
+	},
 	pezsp_runtime::traits::Block as BlockT,
 	*,
 };
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 type HostFunctions = pezsp_io::BizinikiwiHostFunctions;
 
@@ -38,6 +46,14 @@ pub(crate) type FullClient =
 type FullBackend = pezsc_service::TFullBackend<Block>;
 type FullSelectChain = pezsc_consensus::LongestChain<FullBackend, Block>;
 
+type GrandpaBlockImport =
+	pezsc_consensus_grandpa::GrandpaBlockImport<FullBackend, Block, FullClient, FullSelectChain>;
+type GrandpaLinkHalf = pezsc_consensus_grandpa::LinkHalf<Block, FullClient, FullSelectChain>;
+
+/// The minimum period of blocks on which justifications will be
+/// imported and generated.
+const GRANDPA_JUSTIFICATION_PERIOD: u32 = 512;
+
 /// Assembly of PartialComponents (enough to run chain ops subcommands)
 pub type Service = pezsc_service::PartialComponents<
 	FullClient,
@@ -45,10 +61,31 @@ pub type Service = pezsc_service::PartialComponents<
 	FullSelectChain,
 	pezsc_consensus::DefaultImportQueue<Block>,
 	pezsc_transaction_pool::TransactionPoolHandle<Block, FullClient>,
-	Option<Telemetry>,
+	(Option<Telemetry>, Option<(GrandpaBlockImport, GrandpaLinkHalf)>),
 >;
 
-pub fn new_partial(config: &Configuration) -> Result<Service, ServiceError> {
+/// Inherent data for a block sealed by manual or instant seal on top of `parent`.
+///
+/// Sealed blocks carry an Aura pre-digest derived from their timestamp, and the runtime requires
+/// the slot to increase with every block. When blocks are sealed faster than the slot duration,
+/// the timestamp is therefore moved to the first slot after the parent's.
+fn seal_inherent_data(
+	client: &FullClient,
+	parent: <Block as BlockT>::Hash,
+) -> Result<pezsp_timestamp::InherentDataProvider, Box<dyn std::error::Error + Send + Sync>> {
+	let slot_duration = pezsc_consensus_aura::standalone::slot_duration_at(client, parent)?;
+	let header = client.header(parent)?.ok_or("parent header not found")?;
+	let parent_slot =
+		pezsc_consensus_aura::standalone::find_pre_digest::<Block, AuraSignature>(&header)
+			.map_err(|e| format!("failed to read the Aura slot of the parent: {e:?}"))?;
+
+	let earliest = (*parent_slot + 1) * slot_duration.as_millis();
+	let timestamp = pezsp_timestamp::Timestamp::current().max(earliest.into());
+
+	Ok(pezsp_timestamp::InherentDataProvider::new(timestamp))
+}
+
+pub fn new_partial(config: &Configuration, consensus: &Consensus) -> Result<Service, ServiceError> {
 	let telemetry = config
 		.telemetry_endpoints
 		.clone()
@@ -88,11 +125,59 @@ pub fn new_partial(config: &Configuration) -> Result<Service, ServiceError> {
 		.build(),
 	);
 
-	let import_queue = pezsc_consensus_manual_seal::import_queue(
-		Box::new(client.clone()),
-		&task_manager.spawn_essential_handle(),
-		config.prometheus_registry(),
-	);
+	let (import_queue, grandpa) = match consensus {
+		Consensus::Aura => {
+			let (grandpa_block_import, grandpa_link) = pezsc_consensus_grandpa::block_import(
+				client.clone(),
+				GRANDPA_JUSTIFICATION_PERIOD,
+				&client,
+				select_chain.clone(),
+				telemetry.as_ref().map(|x| x.handle()),
+			)?;
+
+			let cidp_client = client.clone();
+			let import_queue = pezsc_consensus_aura::import_queue::<AuraPair, _, _, _, _, _>(
+				ImportQueueParams {
+					block_import: grandpa_block_import.clone(),
+					justification_import: Some(Box::new(grandpa_block_import.clone())),
+					client: client.clone(),
+					create_inherent_data_providers: move |parent_hash, _| {
+						let cidp_client = cidp_client.clone();
+						async move {
+							let slot_duration = pezsc_consensus_aura::standalone::slot_duration_at(
+								&*cidp_client,
+								parent_hash,
+							)?;
+							let timestamp =
+								pezsp_timestamp::InherentDataProvider::from_system_time();
+							let slot = pezsp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+								*timestamp,
+								slot_duration,
+							);
+
+							Ok((slot, timestamp))
+						}
+					},
+					spawner: &task_manager.spawn_essential_handle(),
+					registry: config.prometheus_registry(),
+					check_for_equivocation: Default::default(),
+					telemetry: telemetry.as_ref().map(|x| x.handle()),
+					compatibility_mode: Default::default(),
+				},
+			)?;
+
+			(import_queue, Some((grandpa_block_import, grandpa_link)))
+		},
+		_ => {
+			let import_queue = pezsc_consensus_manual_seal::import_queue(
+				Box::new(client.clone()),
+				&task_manager.spawn_essential_handle(),
+				config.prometheus_registry(),
+			);
+
+			(import_queue, None)
+		},
+	};
 
 	Ok(pezsc_service::PartialComponents {
 		client,
@@ -102,7 +187,7 @@ pub fn new_partial(config: &Configuration) -> Result<Service, ServiceError> {
 		keystore_container,
 		select_chain,
 		transaction_pool,
-		other: (telemetry),
+		other: (telemetry, grandpa),
 	})
 }
 
@@ -110,6 +195,7 @@ pub fn new_partial(config: &Configuration) -> Result<Service, ServiceError> {
 pub fn new_full<Network: pezsc_network::NetworkBackend<Block, <Block as BlockT>::Hash>>(
 	config: Configuration,
 	consensus: Consensus,
+	skip_empty_blocks: bool,
 ) -> Result<TaskManager, ServiceError> {
 	let pezsc_service::PartialComponents {
 		client,
@@ -119,10 +205,10 @@ pub fn new_full<Network: pezsc_network::NetworkBackend<Block, <Block as BlockT>:
 		keystore_container,
 		select_chain,
 		transaction_pool,
-		other: mut telemetry,
-	} = new_partial(&config)?;
+		other: (mut telemetry, grandpa),
+	} = new_partial(&config, &consensus)?;
 
-	let net_config = pezsc_network::config::FullNetworkConfiguration::<
+	let mut net_config = pezsc_network::config::FullNetworkConfiguration::<
 		Block,
 		<Block as BlockT>::Hash,
 		Network,
@@ -134,6 +220,35 @@ pub fn new_full<Network: pezsc_network::NetworkBackend<Block, <Block as BlockT>:
 		config.prometheus_config.as_ref().map(|cfg| &cfg.registry),
 	);
 
+	// GRANDPA needs its own notification protocol and provides warp sync proofs.
+	let (grandpa, warp_sync_config) = match grandpa {
+		Some((block_import, link)) => {
+			let protocol_name = pezsc_consensus_grandpa::protocol_standard_name(
+				&client.chain_info().genesis_hash,
+				&config.chain_spec,
+			);
+			let (protocol_config, notification_service) =
+				pezsc_consensus_grandpa::grandpa_peers_set_config::<_, Network>(
+					protocol_name.clone(),
+					metrics.clone(),
+					net_config.peer_store_handle(),
+				);
+			net_config.add_notification_protocol(protocol_config);
+
+			let warp_sync = Arc::new(pezsc_consensus_grandpa::warp_proof::NetworkProvider::new(
+				backend.clone(),
+				link.shared_authority_set().clone(),
+				Vec::default(),
+			));
+
+			(
+				Some((block_import, link, protocol_name, notification_service)),
+				Some(WarpSyncConfig::WithProvider(warp_sync)),
+			)
+		},
+		None => (None, None),
+	};
+
 	let (network, system_rpc_tx, tx_handler_controller, sync_service) =
 		pezsc_service::build_network(pezsc_service::BuildNetworkParams {
 			config: &config,
@@ -143,7 +258,7 @@ pub fn new_full<Network: pezsc_network::NetworkBackend<Block, <Block as BlockT>:
 			spawn_handle: task_manager.spawn_handle(),
 			import_queue,
 			block_announce_validator_builder: None,
-			warp_sync_config: None,
+			warp_sync_config,
 			block_relay: None,
 			metrics,
 		})?;
@@ -169,20 +284,34 @@ pub fn new_full<Network: pezsc_network::NetworkBackend<Block, <Block as BlockT>:
 		);
 	}
 
+	// Manual seal is driven by the commands sent over this channel, both by the block time
+	// timer and by the `engine_*` RPC methods.
+	let (mut command_sink, commands_stream) = futures::channel::mpsc::channel(1024);
+
 	let rpc_extensions_builder = {
 		let client = client.clone();
 		let pool = transaction_pool.clone();
+		let command_sink =
+			matches!(consensus, Consensus::ManualSeal(_) | Consensus::ManualSealOnDemand)
+				.then(|| command_sink.clone());
 
 		Box::new(move |_| {
-			let deps = crate::rpc::FullDeps { client: client.clone(), pool: pool.clone() };
+			let deps = crate::rpc::FullDeps {
+				client: client.clone(),
+				pool: pool.clone(),
+				command_sink: command_sink.clone(),
+			};
 			crate::rpc::create_full(deps).map_err(Into::into)
 		})
 	};
 
 	let prometheus_registry = config.prometheus_registry().cloned();
+	let role = config.role;
+	let force_authoring = config.force_authoring;
+	let name = config.network.node_name.clone();
 
 	let _rpc_handlers = pezsc_service::spawn_tasks(pezsc_service::SpawnTasksParams {
-		network,
+		network: network.clone(),
 		client: client.clone(),
 		keystore: keystore_container.keystore(),
 		task_manager: &mut task_manager,
@@ -191,7 +320,7 @@ pub fn new_full<Network: pezsc_network::NetworkBackend<Block, <Block as BlockT>:
 		backend,
 		system_rpc_tx,
 		tx_handler_controller,
-		sync_service,
+		sync_service: sync_service.clone(),
 		config,
 		telemetry: telemetry.as_mut(),
 	})?;
@@ -206,15 +335,17 @@ pub fn new_full<Network: pezsc_network::NetworkBackend<Block, <Block as BlockT>:
 
 	match consensus {
 		Consensus::InstantSeal => {
+			let cidp_client = client.clone();
 			let params = pezsc_consensus_manual_seal::InstantSealParams {
 				block_import: client.clone(),
 				env: proposer,
-				client,
+				client: client.clone(),
 				pool: transaction_pool,
 				select_chain,
-				consensus_data_provider: None,
-				create_inherent_data_providers: move |_, ()| async move {
-					Ok(pezsp_timestamp::InherentDataProvider::from_system_time())
+				consensus_data_provider: Some(Box::new(AuraConsensusDataProvider::new(client))),
+				create_inherent_data_providers: move |parent, ()| {
+					let cidp_client = cidp_client.clone();
+					async move { seal_inherent_data(&cidp_client, parent) }
 				},
 			};
 
@@ -226,31 +357,48 @@ pub fn new_full<Network: pezsc_network::NetworkBackend<Block, <Block as BlockT>:
 				authorship_future,
 			);
 		},
-		Consensus::ManualSeal(block_time) => {
-			let (mut sink, commands_stream) = futures::channel::mpsc::channel(1024);
-			task_manager.spawn_handle().spawn("block_authoring", None, async move {
-				loop {
-					futures_timer::Delay::new(std::time::Duration::from_millis(block_time)).await;
-					sink.try_send(pezsc_consensus_manual_seal::EngineCommand::SealNewBlock {
-						create_empty: true,
-						finalize: true,
-						parent_hash: None,
-						sender: None,
-					})
-					.unwrap();
-				}
-			});
+		Consensus::ManualSeal(_) | Consensus::ManualSealOnDemand => {
+			if let Consensus::ManualSeal(block_time) = consensus {
+				let pool = transaction_pool.clone();
+				task_manager.spawn_handle().spawn("block_authoring", None, async move {
+					loop {
+						futures_timer::Delay::new(std::time::Duration::from_millis(block_time))
+							.await;
+						if skip_empty_blocks && pool.status().ready == 0 {
+							continue;
+						}
+						let seal = pezsc_consensus_manual_seal::EngineCommand::SealNewBlock {
+							create_empty: true,
+							finalize: true,
+							parent_hash: None,
+							sender: None,
+						};
+						// The queue is shared with the manual seal RPC, which may have filled it.
+						if let Err(e) = command_sink.try_send(seal) {
+							if e.is_disconnected() {
+								break;
+							}
+							log::warn!(
+								target: "manual-seal",
+								"Skipping a block: the queue of seal commands is full",
+							);
+						}
+					}
+				});
+			}
 
+			let cidp_client = client.clone();
 			let params = pezsc_consensus_manual_seal::ManualSealParams {
 				block_import: client.clone(),
 				env: proposer,
-				client,
+				client: client.clone(),
 				pool: transaction_pool,
 				select_chain,
 				commands_stream: Box::pin(commands_stream),
-				consensus_data_provider: None,
-				create_inherent_data_providers: move |_, ()| async move {
-					Ok(pezsp_timestamp::InherentDataProvider::from_system_time())
+				consensus_data_provider: Some(Box::new(AuraConsensusDataProvider::new(client))),
+				create_inherent_data_providers: move |parent, ()| {
+					let cidp_client = cidp_client.clone();
+					async move { seal_inherent_data(&cidp_client, parent) }
 				},
 			};
 			let authorship_future = pezsc_consensus_manual_seal::run_manual_seal(params);
@@ -261,7 +409,81 @@ pub fn new_full<Network: pezsc_network::NetworkBackend<Block, <Block as BlockT>:
 				authorship_future,
 			);
 		},
-		_ => {},
+		Consensus::Aura => {
+			let (block_import, grandpa_link, grandpa_protocol_name, grandpa_notification_service) =
+				grandpa.expect("GRANDPA is set up by `new_partial` for Aura; qed");
+
+			if role.is_authority() {
+				let slot_duration = pezsc_consensus_aura::slot_duration(&*client)?;
+				let params = StartAuraParams {
+					slot_duration,
+					client: client.clone(),
+					select_chain,
+					block_import,
+					proposer_factory: proposer,
+					create_inherent_data_providers: move |_, ()| async move {
+						let timestamp = pezsp_timestamp::InherentDataProvider::from_system_time();
+						let slot = pezsp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+							*timestamp,
+							slot_duration,
+						);
+
+						Ok((slot, timestamp))
+					},
+					force_authoring,
+					backoff_authoring_blocks: Option::<()>::None,
+					keystore: keystore_container.keystore(),
+					sync_oracle: sync_service.clone(),
+					justification_sync_link: sync_service.clone(),
+					block_proposal_slot_portion: SlotProportion::new(2f32 / 3f32),
+					max_block_proposal_slot_portion: None,
+					telemetry: telemetry.as_ref().map(|x| x.handle()),
+					compatibility_mode: Default::default(),
+				};
+				let aura = start_aura::<AuraPair, _, _, _, _, _, _, _, _, _, _>(params)?;
+
+				task_manager.spawn_essential_handle().spawn_blocking(
+					"aura",
+					Some("block-authoring"),
+					aura,
+				);
+			}
+
+			let grandpa_config = pezsc_consensus_grandpa::Config {
+				gossip_duration: Duration::from_millis(333),
+				justification_generation_period: GRANDPA_JUSTIFICATION_PERIOD,
+				name: Some(name),
+				observer_enabled: false,
+				keystore: role.is_authority().then(|| keystore_container.keystore()),
+				local_role: role,
+				telemetry: telemetry.as_ref().map(|x| x.handle()),
+				protocol_name: grandpa_protocol_name,
+			};
+
+			// Non-authorities run the voter without a keystore, so they follow finality
+			// without voting.
+			let grandpa_voter = pezsc_consensus_grandpa::run_grandpa_voter(
+				pezsc_consensus_grandpa::GrandpaParams {
+					config: grandpa_config,
+					link: grandpa_link,
+					network,
+					sync: Arc::new(sync_service),
+					notification_service: grandpa_notification_service,
+					voting_rule: pezsc_consensus_grandpa::VotingRulesBuilder::default().build(),
+					prometheus_registry,
+					shared_voter_state: SharedVoterState::empty(),
+					telemetry: telemetry.as_ref().map(|x| x.handle()),
+					offchain_tx_pool_factory: OffchainTransactionPoolFactory::new(transaction_pool),
+				},
+			)?;
+
+			task_manager.spawn_essential_handle().spawn_blocking(
+				"grandpa-voter",
+				None,
+				grandpa_voter,
+			);
+		},
+		Consensus::None => {},
 	}
 
 	Ok(task_manager)
@@ -0,0 +1,110 @@
+use codec::Encode;
+use jsonrpsee::{
+	core::client::ClientT,
+	http_client::{HttpClient, HttpClientBuilder},
+	rpc_params,
+};
+use pez_minimal_template_runtime::{Runtime, RuntimeCall, TxExtension};
+use pezkuwi_sdk::{
+	pezframe_system,
+	pezkuwi_sdk_frame::runtime::prelude::ExtrinsicFor,
+	pezpallet_asset_tx_payment::ChargeAssetTxPayment,
+	pezsp_core::{Bytes, H256},
+	pezsp_keyring::Sr25519Keyring,
+	pezsp_runtime::generic::{Era, SignedPayload},
+};
+use std::{
+	net::TcpListener,
+	process::{Child, Command, Stdio},
+	time::Duration,
+};
+
+/// How long to wait for a freshly spawned node to answer RPC requests.
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// A dev node running in a child process, killed when dropped.
+pub struct Node {
+	process: Child,
+	/// Client connected to the node's RPC server.
+	pub rpc: HttpClient,
+}
+
+impl Drop for Node {
+	fn drop(&mut self) {
+		let _ = self.process.kill();
+		let _ = self.process.wait();
+	}
+}
+
+/// Find a port that is free on the local interface.
+fn free_port() -> u16 {
+	TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port()
+}
+
+/// Spawn `pez-minimal-template-node --dev --tmp` with `extra_args` and wait for its RPC server.
+pub async fn start_dev_node(extra_args: &[&str]) -> Node {
+	let port = free_port();
+	let process = Command::new(env!("CARGO_BIN_EXE_pez-minimal-template-node"))
+		.args(["--dev", "--tmp", "--rpc-port", &port.to_string(), "--no-prometheus"])
+		.args(extra_args)
+		.stdout(Stdio::null())
+		.stderr(Stdio::null())
+		.spawn()
+		.expect("failed to spawn the node binary");
+	let rpc = HttpClientBuilder::default().build(format!("http://127.0.0.1:{port}")).unwrap();
+	let node = Node { process, rpc };
+
+	let started = std::time::Instant::now();
+	while node.rpc.request::<serde_json::Value, _>("system_health", rpc_params![]).await.is_err() {
+		assert!(
+			started.elapsed() < STARTUP_TIMEOUT,
+			"node did not start within {STARTUP_TIMEOUT:?}"
+		);
+		tokio::time::sleep(Duration::from_millis(250)).await;
+	}
+
+	node
+}
+
+/// An immortal extrinsic calling `call`, signed by Alice with `nonce` and no tip, paying its fee
+/// in the native token, for the runtime the node currently runs.
+pub async fn signed_by_alice(node: &Node, call: RuntimeCall, nonce: u32) -> Bytes {
+	let genesis_hash: H256 = node.rpc.request("chain_getBlockHash", rpc_params![0]).await.unwrap();
+	let version: serde_json::Value =
+		node.rpc.request("state_getRuntimeVersion", rpc_params![]).await.unwrap();
+	let version_field =
+		|field: &str| version[field].as_u64().expect("the version is a number") as u32;
+
+	let tx_ext: TxExtension = (
+		pezframe_system::CheckNonZeroSender::new(),
+		pezframe_system::CheckSpecVersion::new(),
+		pezframe_system::CheckTxVersion::new(),
+		pezframe_system::CheckGenesis::new(),
+		pezframe_system::CheckEra::from(Era::Immortal),
+		pezframe_system::CheckNonce::from(nonce),
+		pezframe_system::CheckWeight::new(),
+		ChargeAssetTxPayment::from(0, None),
+		pezframe_system::WeightReclaim::new(),
+	);
+	let implicit = (
+		(),
+		version_field("specVersion"),
+		version_field("transactionVersion"),
+		genesis_hash,
+		genesis_hash,
+		(),
+		(),
+		(),
+		(),
+	);
+	let payload = SignedPayload::from_raw(call.clone(), tx_ext.clone(), implicit);
+	let signature = payload.using_encoded(|payload| Sr25519Keyring::Alice.sign(payload));
+
+	let xt = ExtrinsicFor::<Runtime>::new_signed(
+		call,
+		Sr25519Keyring::Alice.to_account_id().into(),
+		signature.into(),
+		tx_ext,
+	);
+	Bytes(xt.encode())
+}
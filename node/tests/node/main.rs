@@ -0,0 +1,8 @@
+//! Tests that spawn the node binary on a dev chain and talk to it over RPC.
+
+mod common;
+mod payment;
+// Upgrades through `Sudo`, which the `governance` feature removes.
+#[cfg(not(feature = "governance"))]
+mod upgrade;
+mod view_functions;
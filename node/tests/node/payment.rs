@@ -0,0 +1,54 @@
+use crate::common::*;
+use jsonrpsee::{core::client::ClientT, rpc_params};
+use pez_minimal_template_runtime::RuntimeCall;
+use pezkuwi_sdk::{pezframe_system, pezsp_core::Bytes};
+use serde_json::Value;
+
+/// A `System::remark` signed by Alice.
+async fn signed_remark(node: &Node) -> Bytes {
+	let call = RuntimeCall::System(pezframe_system::Call::remark { remark: vec![0; 32] });
+	signed_by_alice(node, call, 0).await
+}
+
+/// Read a balance that the RPC returns either as a number or as a decimal or hex string.
+fn balance(value: &Value) -> u128 {
+	match value {
+		Value::Number(n) => n.as_u64().expect("balance fits in a u64") as u128,
+		Value::String(s) => match s.strip_prefix("0x") {
+			Some(hex) => u128::from_str_radix(hex, 16).unwrap(),
+			None => s.parse().unwrap(),
+		},
+		other => panic!("unexpected balance {other}"),
+	}
+}
+
+#[tokio::test]
+async fn query_info_charges_a_fee_for_signed_extrinsics() {
+	let node = start_dev_node(&[]).await;
+	let xt = signed_remark(&node).await;
+
+	let info: Value = node.rpc.request("payment_queryInfo", rpc_params![xt]).await.unwrap();
+
+	assert_eq!(info["class"], "normal");
+	assert!(info["weight"]["ref_time"].as_u64().unwrap() > 0);
+	assert!(balance(&info["partialFee"]) > 0);
+}
+
+#[tokio::test]
+async fn query_fee_details_adds_up_to_the_partial_fee() {
+	let node = start_dev_node(&[]).await;
+	let xt = signed_remark(&node).await;
+
+	let info: Value = node.rpc.request("payment_queryInfo", rpc_params![xt.clone()]).await.unwrap();
+	let details: Value =
+		node.rpc.request("payment_queryFeeDetails", rpc_params![xt]).await.unwrap();
+
+	let inclusion_fee = &details["inclusionFee"];
+	assert!(balance(&inclusion_fee["lenFee"]) > 0);
+	assert_eq!(
+		balance(&inclusion_fee["baseFee"])
+			+ balance(&inclusion_fee["lenFee"])
+			+ balance(&inclusion_fee["adjustedWeightFee"]),
+		balance(&info["partialFee"]),
+	);
+}
@@ -0,0 +1,72 @@
+use crate::common::*;
+use codec::Decode;
+use jsonrpsee::{core::client::ClientT, rpc_params};
+use pez_minimal_template_runtime::{
+	wasm_spec_version_incremented::WASM_BINARY as BUMPED_WASM_BINARY, Runtime, RuntimeCall, VERSION,
+};
+use pezkuwi_sdk::{
+	pezframe_support::storage::StorageValue,
+	pezframe_system::{self, LastRuntimeUpgrade, LastRuntimeUpgradeInfo},
+	pezpallet_balances, pezpallet_sudo,
+	pezsp_core::{Bytes, H256},
+	pezsp_keyring::Sr25519Keyring::{Alice, Bob},
+};
+use serde_json::Value;
+
+async fn seal_block(node: &Node) {
+	let _: Value = node
+		.rpc
+		.request("engine_createBlock", rpc_params![true, true, Option::<H256>::None])
+		.await
+		.unwrap();
+}
+
+async fn best_block_number(node: &Node) -> u64 {
+	let header: Value = node.rpc.request("chain_getHeader", rpc_params![]).await.unwrap();
+	let number = header["number"].as_str().unwrap();
+	u64::from_str_radix(number.trim_start_matches("0x"), 16).unwrap()
+}
+
+/// The runtime upgrade `Executive` last ran the migrations for, as of the best block.
+async fn last_runtime_upgrade(node: &Node) -> LastRuntimeUpgradeInfo {
+	let key = Bytes(LastRuntimeUpgrade::<Runtime>::hashed_key().to_vec());
+	let value: Bytes = node.rpc.request("state_getStorage", rpc_params![key]).await.unwrap();
+	LastRuntimeUpgradeInfo::decode(&mut &value[..]).unwrap()
+}
+
+#[tokio::test]
+async fn sudo_set_code_upgrades_the_runtime() {
+	let node = start_dev_node(&["--consensus", "manual-seal"]).await;
+	let code = BUMPED_WASM_BINARY.expect("the bumped runtime wasm is built").to_vec();
+
+	let set_code = RuntimeCall::System(pezframe_system::Call::set_code { code });
+	let call = RuntimeCall::Sudo(pezpallet_sudo::Call::sudo { call: Box::new(set_code) });
+	let xt = signed_by_alice(&node, call, 0).await;
+	let _: H256 = node.rpc.request("author_submitExtrinsic", rpc_params![xt]).await.unwrap();
+	seal_block(&node).await;
+
+	let version: Value = node.rpc.request("state_getRuntimeVersion", rpc_params![]).await.unwrap();
+	assert_eq!(version["specVersion"], VERSION.spec_version + 1);
+
+	// The next block runs the runtime upgrade, and includes a transfer signed for the new
+	// `spec_version`.
+	let transfer = RuntimeCall::Balances(pezpallet_balances::Call::transfer_keep_alive {
+		dest: Bob.to_account_id().into(),
+		value: 1_000_000_000_000,
+	});
+	let xt = signed_by_alice(&node, transfer, 1).await;
+	let _: H256 = node.rpc.request("author_submitExtrinsic", rpc_params![xt]).await.unwrap();
+	seal_block(&node).await;
+
+	assert_eq!(last_runtime_upgrade(&node).await.spec_version.0, VERSION.spec_version + 1);
+	let pending: Vec<Bytes> =
+		node.rpc.request("author_pendingExtrinsics", rpc_params![]).await.unwrap();
+	assert!(pending.is_empty());
+	let alice = Alice.to_account_id().to_string();
+	let nonce: u32 = node.rpc.request("system_accountNextIndex", rpc_params![alice]).await.unwrap();
+	assert_eq!(nonce, 2);
+
+	// The chain keeps building blocks on top of the new runtime.
+	seal_block(&node).await;
+	assert_eq!(best_block_number(&node).await, 3);
+}
@@ -0,0 +1,35 @@
+use crate::common::*;
+use codec::{Decode, Encode};
+use jsonrpsee::{core::client::ClientT, rpc_params};
+use pez_minimal_template_runtime::Runtime;
+use pezkuwi_sdk::{
+	pezframe_support::view_functions::{ViewFunction, ViewFunctionDispatchError},
+	pezsp_core::{Bytes, H256},
+};
+use pezpallet_minimal_template::GetValueViewFunction;
+
+/// Execute `query` on the block `at` through the `RuntimeViewFunction` runtime API.
+async fn call_view_function<Q: ViewFunction + Encode>(node: &Node, query: Q, at: H256) -> Vec<u8> {
+	let input = Bytes((Q::id(), query.encode()).encode());
+	let output: Bytes = node
+		.rpc
+		.request("state_call", rpc_params!["RuntimeViewFunction_execute_view_function", input, at])
+		.await
+		.unwrap();
+
+	Result::<Vec<u8>, ViewFunctionDispatchError>::decode(&mut &output[..])
+		.unwrap()
+		.expect("the view function exists")
+}
+
+#[tokio::test]
+async fn get_value_returns_the_dev_genesis_value() {
+	let node = start_dev_node(&[]).await;
+	// The template's offchain workers update the value in later blocks.
+	let genesis_hash: H256 = node.rpc.request("chain_getBlockHash", rpc_params![0]).await.unwrap();
+
+	let output =
+		call_view_function(&node, GetValueViewFunction::<Runtime>::new(), genesis_hash).await;
+
+	assert_eq!(Option::<u32>::decode(&mut &output[..]).unwrap(), Some(42));
+}
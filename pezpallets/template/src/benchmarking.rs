@@ -0,0 +1,126 @@
+//! Benchmarks for the calls of the template pezpallet.
+
+use super::*;
+use crate::migrations::prune::PruneStoreCounts;
+use pezkuwi_sdk::{
+	pezframe_support::{
+		migrations::SteppedMigration,
+		traits::fungible::{Inspect, Mutate},
+		weights::WeightMeter,
+	},
+	pezframe_system::RawOrigin,
+	pezkuwi_sdk_frame::benchmarking::prelude::*,
+};
+
+/// Give `who` enough to hold the deposit of the longest entry twice over.
+fn fund<T: Config<I>, I: 'static>(who: &T::AccountId) {
+	let deposit = Pezpallet::<T, I>::entry_deposit(T::MaxEntryLen::get());
+	let balance =
+		T::Currency::minimum_balance().saturating_add(deposit.saturating_mul(2u32.into()));
+	T::Currency::set_balance(who, balance);
+}
+
+#[instance_benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_value() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 100);
+
+		assert_eq!(Value::<T, I>::get().map(|r| r.value), Some(100));
+	}
+
+	#[benchmark]
+	fn increment() {
+		let caller: T::AccountId = whitelisted_caller();
+		Value::<T, I>::put(ValueRecord { value: 100, owner: None, updated_at: Zero::zero() });
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 1);
+
+		assert_eq!(Value::<T, I>::get().map(|r| r.value), Some(101));
+	}
+
+	#[benchmark]
+	fn clear_value() {
+		let caller: T::AccountId = whitelisted_caller();
+		Value::<T, I>::put(ValueRecord { value: 100, owner: None, updated_at: Zero::zero() });
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller));
+
+		assert_eq!(Value::<T, I>::get(), None);
+	}
+
+	#[benchmark]
+	fn force_set_value() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 100);
+
+		assert_eq!(Value::<T, I>::get().map(|r| r.value), Some(100));
+		Ok(())
+	}
+
+	/// Replacing an empty entry with one of `l` bytes, which raises the deposit.
+	#[benchmark]
+	fn set_entry(l: Linear<0, { T::MaxEntryLen::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T, I>(&caller);
+		Pezpallet::<T, I>::set_entry(RawOrigin::Signed(caller.clone()).into(), Default::default())
+			.unwrap();
+		let entry = BoundedVec::try_from(alloc::vec![1u8; l as usize]).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), entry);
+
+		assert_eq!(Entries::<T, I>::get(&caller).map(|e| e.len()), Some(l as usize));
+	}
+
+	#[benchmark]
+	fn clear_entry() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T, I>(&caller);
+		let entry = BoundedVec::try_from(alloc::vec![1u8; T::MaxEntryLen::get() as usize]).unwrap();
+		Pezpallet::<T, I>::set_entry(RawOrigin::Signed(caller.clone()).into(), entry).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(!Entries::<T, I>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn submit_value_unsigned() {
+		let block_number = pezkuwi_sdk::pezframe_system::Pezpallet::<T>::block_number();
+
+		#[extrinsic_call]
+		_(RawOrigin::None, block_number, 100);
+
+		assert_eq!(Value::<T, I>::get().map(|r| r.value), Some(100));
+	}
+
+	/// A step of [`PruneStoreCounts`] that removes the count of a reaped account.
+	#[benchmark]
+	fn prune_store_count_step() {
+		let reaped: T::AccountId = account("reaped", 0, 0);
+		let other: T::AccountId = account("reaped", 1, 0);
+		StoreCount::<T, I>::insert(&reaped, 1);
+		StoreCount::<T, I>::insert(&other, 1);
+		// Room for a single step, so that only one of the counts is looked at.
+		let mut meter = WeightMeter::with_limit(T::WeightInfo::prune_store_count_step());
+
+		#[block]
+		{
+			PruneStoreCounts::<T, I>::step(None, &mut meter).unwrap();
+		}
+
+		assert_eq!(StoreCount::<T, I>::iter_keys().count(), 1);
+	}
+}
@@ -2,24 +2,533 @@
 //!
 //! To get started with this pezpallet, try implementing the guide in
 //! <https://github.com/pezkuwichain/pezkuwi-sdk>
+//!
+//! The pezpallet keeps a single `u32` in [`Value`], together with the account that stored it and
+//! the block it was stored in, and counts how often each account stored a value in
+//! [`StoreCount`]. The value can be seeded through [`GenesisConfig`], and the pezpallet exposes
+//! signed calls to set, increment and clear it:
+//!
+//! - [`Call::set_value`]: stores a new value.
+//! - [`Call::increment`]: adds to the stored value, failing on overflow.
+//! - [`Call::clear_value`]: removes the stored value.
+//!
+//! [`Call::force_set_value`] lets [`Config::AdminOrigin`] overwrite the value without becoming
+//! its owner.
+//!
+//! Besides the shared value, every account can keep an entry of up to [`Config::MaxEntryLen`]
+//! bytes in [`Entries`]. Storing one holds a deposit, see [`Pezpallet::entry_deposit`], from the
+//! account under [`HoldReason::EntryDeposit`], which is released in full when it is cleared:
+//!
+//! - [`Call::set_entry`]: stores or replaces the caller's entry, adjusting the deposit.
+//! - [`Call::clear_entry`]: removes the caller's entry and releases the deposit.
+//!
+//! Front ends can read the value through the view functions [`Pezpallet::get_value`],
+//! [`Pezpallet::get_value_record`] and [`Pezpallet::get_store_count`] instead of decoding raw
+//! storage.
+//!
+//! The pezpallet's offchain worker submits [`Pezpallet::next_value`], the stored value plus one,
+//! at most once every [`Config::UnsignedInterval`] blocks. If the node's keystore holds a key of
+//! [`Config::AuthorityId`], such as a [`crypto`] key of [`KEY_TYPE`], the worker signs
+//! [`Call::set_value`] with it, so the key's account pays the fee, and records in the node's
+//! offchain storage when it last did. Otherwise it submits the unsigned
+//! [`Call::submit_value_unsigned`], which is only valid once every interval and only if it
+//! carries the expected update.
+//!
+//! The pezpallet is instantiable, so a runtime can hold several independent registers, each
+//! with its own storage, events and admin origin. The offchain workers of all instances run on
+//! top of the same block and sign with the account's nonce in that block, so each instance needs
+//! an `AuthorityId` of its own key type, held by a different account.
+//!
+//! Storage migrations between versions of the pezpallet live in [`migrations`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use pezkuwi_sdk::pezkuwi_sdk_frame::deps::pezframe_support::pezpallet_prelude::*;
+extern crate alloc;
+
+#[cfg(feature = "try-runtime")]
+use pezkuwi_sdk::pezsp_runtime::TryRuntimeError;
+use pezkuwi_sdk::{
+	pezframe_support::traits::{
+		fungible::{self, InspectHold, MutateHold},
+		tokens::Precision,
+		PalletInfoAccess,
+	},
+	pezframe_system::{
+		offchain::{
+			AppCrypto, CreateBare, CreateSignedTransaction, SendSignedTransaction, Signer,
+			SubmitTransaction,
+		},
+		pezpallet_prelude::*,
+	},
+	pezkuwi_sdk_frame::deps::pezframe_support::pezpallet_prelude::*,
+	pezsp_runtime::{
+		offchain::storage::{StorageRetrievalError, StorageValueRef},
+		traits::{Saturating, Zero},
+		KeyTypeId, SaturatedConversion,
+	},
+};
 
 // Re-export all pezpallet parts, this is needed to properly import the pezpallet into the runtime.
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
+pub mod weights;
+pub use weights::*;
+
+/// The key type of the keys the offchain worker signs its transactions with.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"tmpl");
+
+/// The sr25519 keys the offchain worker signs with, kept in the node's keystore under
+/// [`KEY_TYPE`].
+pub mod crypto {
+	use super::KEY_TYPE;
+	use pezkuwi_sdk::{
+		pezframe_system::offchain::AppCrypto,
+		pezsp_runtime::{
+			app_crypto::{app_crypto, sr25519},
+			MultiSignature, MultiSigner,
+		},
+	};
+
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// Signs on behalf of the account of a [`Public`] key, for runtimes whose accounts are
+	/// identified by a [`MultiSigner`].
+	pub struct TemplateAuthId;
+
+	impl AppCrypto<MultiSigner, MultiSignature> for TemplateAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sr25519::Signature;
+		type GenericPublic = sr25519::Public;
+	}
+}
+
 #[pezkuwi_sdk::pezframe_support::pezpallet]
 pub mod pallet {
 	use super::*;
 
 	#[pezpallet::config]
-	pub trait Config: pezkuwi_sdk::pezframe_system::Config {}
+	pub trait Config<I: 'static = ()>:
+		CreateSignedTransaction<Call<Self, I>>
+		+ CreateBare<Call<Self, I>>
+		+ pezkuwi_sdk::pezframe_system::Config
+	{
+		/// The origin allowed to call [`Call::force_set_value`].
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason<I>>;
+
+		/// The currency entry deposits are held in.
+		type Currency: fungible::Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+		/// The maximum length of an entry, in bytes.
+		#[pezpallet::constant]
+		type MaxEntryLen: Get<u32>;
+
+		/// The deposit held for storing an entry, whatever its length.
+		#[pezpallet::constant]
+		type EntryDepositBase: Get<BalanceOf<Self, I>>;
+
+		/// The deposit held for each byte of an entry.
+		#[pezpallet::constant]
+		type EntryDepositPerByte: Get<BalanceOf<Self, I>>;
+
+		/// The keys the offchain worker signs [`Call::set_value`] with. Instances must not share a
+		/// key type, or they sign with the same nonce.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// The priority of [`Call::submit_value_unsigned`] in the transaction pool.
+		#[pezpallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// How many blocks must pass between two [`Call::submit_value_unsigned`].
+		///
+		/// An unsigned submission is dropped from the pool after as many blocks.
+		#[pezpallet::constant]
+		type UnsignedInterval: Get<BlockNumberFor<Self>>;
+
+		/// Weight information for the calls of this pezpallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pezpallet::pezpallet]
-	pub struct Pezpallet<T>(_);
+	#[pezpallet::storage_version(STORAGE_VERSION)]
+	pub struct Pezpallet<T, I = ()>(_);
+
+	/// A value stored by the pezpallet along with who stored it and when.
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct ValueRecord<AccountId, BlockNumber> {
+		/// The stored value.
+		pub value: u32,
+		/// The account that last stored the value, `None` if it was set at genesis or by a
+		/// migration.
+		pub owner: Option<AccountId>,
+		/// The block in which the value was last stored.
+		pub updated_at: BlockNumber,
+	}
+
+	/// The [`ValueRecord`] of a runtime.
+	pub type ValueRecordOf<T> =
+		ValueRecord<<T as pezkuwi_sdk::pezframe_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	/// The balance type of [`Config::Currency`].
+	pub type BalanceOf<T, I = ()> = <<T as Config<I>>::Currency as fungible::Inspect<
+		<T as pezkuwi_sdk::pezframe_system::Config>::AccountId,
+	>>::Balance;
+
+	/// The reasons the pezpallet holds funds for.
+	#[pezpallet::composite_enum]
+	pub enum HoldReason<I: 'static = ()> {
+		/// The deposit for an entry in [`Entries`].
+		EntryDeposit,
+	}
 
 	#[pezpallet::storage]
-	pub type Value<T> = StorageValue<Value = u32>;
+	pub type Value<T: Config<I>, I: 'static = ()> = StorageValue<Value = ValueRecordOf<T>>;
+
+	/// How many times each account has stored a value.
+	///
+	/// Entries are not removed when an account is reaped; see
+	/// [`crate::migrations::prune::PruneStoreCounts`].
+	#[pezpallet::storage]
+	pub type StoreCount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The entry of each account that stored one.
+	#[pezpallet::storage]
+	pub type Entries<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<u8, T::MaxEntryLen>>;
+
+	/// The first block in which [`Call::submit_value_unsigned`] is accepted again.
+	#[pezpallet::storage]
+	pub type NextUnsignedAt<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	#[pezpallet::genesis_config]
+	#[derive(DefaultNoBound)]
+	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+		/// The value to store in [`Value`] at genesis, if any.
+		pub value: Option<u32>,
+		#[serde(skip)]
+		pub _config: core::marker::PhantomData<(T, I)>,
+	}
+
+	#[pezpallet::genesis_build]
+	impl<T: Config<I>, I: 'static> BuildGenesisConfig for GenesisConfig<T, I> {
+		fn build(&self) {
+			if let Some(value) = self.value {
+				Value::<T, I>::put(ValueRecord { value, owner: None, updated_at: Zero::zero() });
+			}
+		}
+	}
+
+	#[pezpallet::event]
+	#[pezpallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A new value was stored.
+		ValueStored { who: T::AccountId, value: u32 },
+		/// The stored value was removed.
+		ValueCleared { who: T::AccountId },
+		/// A new value was stored by the admin origin.
+		ValueForced { value: u32 },
+		/// A new value was submitted by an offchain worker, without an owner.
+		ValueSubmitted { value: u32 },
+		/// `who` stored an entry, for which `deposit` is now held.
+		EntrySet { who: T::AccountId, deposit: BalanceOf<T, I> },
+		/// `who` cleared their entry, releasing its `deposit`.
+		EntryCleared { who: T::AccountId, deposit: BalanceOf<T, I> },
+	}
+
+	#[pezpallet::error]
+	pub enum Error<T, I = ()> {
+		/// There is no value stored.
+		NoneValue,
+		/// The operation would overflow the stored value.
+		StorageOverflow,
+		/// The account has no entry.
+		NoEntry,
+	}
+
+	#[pezpallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pezpallet<T, I> {
+		/// Submit [`Pezpallet::next_value`] at most once every [`Config::UnsignedInterval`]
+		/// blocks, signed if the keystore holds a [`Config::AuthorityId`] key and unsigned
+		/// otherwise.
+		fn offchain_worker(block_number: BlockNumberFor<T>) {
+			let value = Self::next_value();
+			let signer = Signer::<T, T::AuthorityId>::any_account();
+
+			// A submission the pool rejects, e.g. because the previous one is still pending, is
+			// simply made again on top of a later block.
+			if signer.can_sign() {
+				if Self::may_sign_at(block_number) {
+					let _ = signer.send_signed_transaction(|_| Call::set_value { value });
+				}
+			} else if block_number >= NextUnsignedAt::<T, I>::get() {
+				let call = Call::submit_value_unsigned { block_number, value };
+				let _ = SubmitTransaction::<T, Call<T, I>>::submit_transaction(T::create_bare(
+					call.into(),
+				));
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
+
+	#[pezpallet::call]
+	impl<T: Config<I>, I: 'static> Pezpallet<T, I> {
+		/// Store `value`, overwriting whatever was there before.
+		#[pezpallet::call_index(0)]
+		#[pezpallet::weight(T::WeightInfo::set_value())]
+		pub fn set_value(origin: OriginFor<T>, value: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::store(who, value);
+
+			Ok(())
+		}
+
+		/// Add `by` to the stored value.
+		///
+		/// Fails with [`Error::NoneValue`] if nothing is stored and with
+		/// [`Error::StorageOverflow`] if the result does not fit in a `u32`.
+		#[pezpallet::call_index(1)]
+		#[pezpallet::weight(T::WeightInfo::increment())]
+		pub fn increment(origin: OriginFor<T>, by: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let current = Value::<T, I>::get().ok_or(Error::<T, I>::NoneValue)?;
+			let value = current.value.checked_add(by).ok_or(Error::<T, I>::StorageOverflow)?;
+
+			Self::store(who, value);
+
+			Ok(())
+		}
+
+		/// Remove the stored value.
+		///
+		/// Fails with [`Error::NoneValue`] if nothing is stored.
+		#[pezpallet::call_index(2)]
+		#[pezpallet::weight(T::WeightInfo::clear_value())]
+		pub fn clear_value(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Value::<T, I>::exists(), Error::<T, I>::NoneValue);
+
+			Value::<T, I>::kill();
+			Self::deposit_event(Event::ValueCleared { who });
+
+			Ok(())
+		}
+
+		/// Store `value` without an owner, overwriting whatever was there before.
+		///
+		/// Can only be called by [`Config::AdminOrigin`].
+		#[pezpallet::call_index(3)]
+		#[pezpallet::weight(T::WeightInfo::force_set_value())]
+		pub fn force_set_value(origin: OriginFor<T>, value: u32) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let updated_at = pezkuwi_sdk::pezframe_system::Pezpallet::<T>::block_number();
+			Value::<T, I>::put(ValueRecord { value, owner: None, updated_at });
+			Self::deposit_event(Event::ValueForced { value });
+
+			Ok(())
+		}
+
+		/// Store `entry` as the caller's entry, replacing the one they had.
+		///
+		/// The deposit held from the caller is raised or lowered to
+		/// [`Pezpallet::entry_deposit`] of the new entry's length. Fails if the caller can't
+		/// afford it.
+		#[pezpallet::call_index(4)]
+		#[pezpallet::weight(T::WeightInfo::set_entry(entry.len() as u32))]
+		pub fn set_entry(
+			origin: OriginFor<T>,
+			entry: BoundedVec<u8, T::MaxEntryLen>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let reason = HoldReason::<I>::EntryDeposit.into();
+			let held = T::Currency::balance_on_hold(&reason, &who);
+			let deposit = Self::entry_deposit(entry.len() as u32);
+			if deposit > held {
+				T::Currency::hold(&reason, &who, deposit.saturating_sub(held))?;
+			} else if deposit < held {
+				T::Currency::release(
+					&reason,
+					&who,
+					held.saturating_sub(deposit),
+					Precision::Exact,
+				)?;
+			}
+
+			Entries::<T, I>::insert(&who, entry);
+			Self::deposit_event(Event::EntrySet { who, deposit });
+
+			Ok(())
+		}
+
+		/// Remove the caller's entry and release its deposit.
+		///
+		/// Fails with [`Error::NoEntry`] if the caller has no entry.
+		#[pezpallet::call_index(5)]
+		#[pezpallet::weight(T::WeightInfo::clear_entry())]
+		pub fn clear_entry(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Entries::<T, I>::contains_key(&who), Error::<T, I>::NoEntry);
+
+			Entries::<T, I>::remove(&who);
+			let deposit = T::Currency::release_all(
+				&HoldReason::<I>::EntryDeposit.into(),
+				&who,
+				Precision::Exact,
+			)?;
+			Self::deposit_event(Event::EntryCleared { who, deposit });
+
+			Ok(())
+		}
+
+		/// Store `value` without an owner, as computed by the offchain worker on top of
+		/// `block_number`. The next one is accepted [`Config::UnsignedInterval`] blocks after it.
+		///
+		/// Must be submitted unsigned. See [`Pezpallet::validate_unsigned`] for when it is valid.
+		#[pezpallet::call_index(6)]
+		#[pezpallet::weight(T::WeightInfo::submit_value_unsigned())]
+		pub fn submit_value_unsigned(
+			origin: OriginFor<T>,
+			block_number: BlockNumberFor<T>,
+			value: u32,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let updated_at = pezkuwi_sdk::pezframe_system::Pezpallet::<T>::block_number();
+			Value::<T, I>::put(ValueRecord { value, owner: None, updated_at });
+			NextUnsignedAt::<T, I>::put(block_number.saturating_add(T::UnsignedInterval::get()));
+			Self::deposit_event(Event::ValueSubmitted { value });
+
+			Ok(())
+		}
+	}
+
+	#[pezpallet::validate_unsigned]
+	impl<T: Config<I>, I: 'static> ValidateUnsigned for Pezpallet<T, I> {
+		type Call = Call<T, I>;
+
+		/// [`Call::submit_value_unsigned`] is valid if it was computed on top of a block no earlier
+		/// than [`NextUnsignedAt`] and no later than the current one, and carries
+		/// [`Pezpallet::next_value`].
+		///
+		/// Only one submission per interval, identified by [`NextUnsignedAt`], is kept in the pool,
+		/// and it is dropped after [`Config::UnsignedInterval`] blocks.
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::submit_value_unsigned { block_number, value } = call else {
+				return InvalidTransaction::Call.into();
+			};
+
+			if *block_number < NextUnsignedAt::<T, I>::get() {
+				return InvalidTransaction::Stale.into();
+			}
+			if *block_number > pezkuwi_sdk::pezframe_system::Pezpallet::<T>::block_number() {
+				return InvalidTransaction::Future.into();
+			}
+			if *value != Self::next_value() {
+				return InvalidTransaction::Call.into();
+			}
+
+			ValidTransaction::with_tag_prefix(<Self as PalletInfoAccess>::name())
+				.priority(T::UnsignedPriority::get())
+				.and_provides(NextUnsignedAt::<T, I>::get())
+				.longevity(T::UnsignedInterval::get().saturated_into())
+				.propagate(true)
+				.build()
+		}
+	}
+
+	#[pezpallet::view_functions]
+	impl<T: Config<I>, I: 'static> Pezpallet<T, I> {
+		/// The stored value, if any.
+		pub fn get_value() -> Option<u32> {
+			Value::<T, I>::get().map(|record| record.value)
+		}
+
+		/// The stored value together with the account that stored it and when.
+		pub fn get_value_record() -> Option<ValueRecordOf<T>> {
+			Value::<T, I>::get()
+		}
+
+		/// How many times `who` has stored a value.
+		pub fn get_store_count(who: T::AccountId) -> u32 {
+			StoreCount::<T, I>::get(who)
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pezpallet<T, I> {
+		/// The deposit held for an entry of `len` bytes.
+		pub fn entry_deposit(len: u32) -> BalanceOf<T, I> {
+			T::EntryDepositPerByte::get()
+				.saturating_mul(len.into())
+				.saturating_add(T::EntryDepositBase::get())
+		}
+
+		/// The update the offchain worker submits: the stored value plus one, saturating, or zero
+		/// if nothing is stored.
+		pub fn next_value() -> u32 {
+			Value::<T, I>::get().map_or(0, |record| record.value.saturating_add(1))
+		}
+
+		/// Whether the offchain worker may sign a transaction on top of `block_number`, which it
+		/// may if it has not signed one in the [`Config::UnsignedInterval`] blocks before. If so,
+		/// `block_number` is recorded in the node's offchain storage as when it last signed.
+		fn may_sign_at(block_number: BlockNumberFor<T>) -> bool {
+			let key = [<Self as PalletInfoAccess>::name().as_bytes(), b"::last_signed_at"].concat();
+			StorageValueRef::persistent(&key)
+				.mutate(|last: Result<Option<BlockNumberFor<T>>, StorageRetrievalError>| {
+					let interval = T::UnsignedInterval::get();
+					match last {
+						Ok(Some(last)) if block_number < last.saturating_add(interval) => Err(()),
+						_ => Ok(block_number),
+					}
+				})
+				.is_ok()
+		}
+
+		/// Store `value` on behalf of `who` in the current block.
+		fn store(who: T::AccountId, value: u32) {
+			let updated_at = pezkuwi_sdk::pezframe_system::Pezpallet::<T>::block_number();
+			Value::<T, I>::put(ValueRecord { value, owner: Some(who.clone()), updated_at });
+			StoreCount::<T, I>::mutate(&who, |count| count.saturating_inc());
+			Self::deposit_event(Event::ValueStored { who, value });
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	impl<T: Config<I>, I: 'static> Pezpallet<T, I> {
+		/// Check the invariants of the pezpallet's storage.
+		///
+		/// Every account with an entry in [`Entries`] must have exactly
+		/// [`Pezpallet::entry_deposit`] of its length held under [`HoldReason::EntryDeposit`].
+		pub fn do_try_state() -> Result<(), TryRuntimeError> {
+			let reason = HoldReason::<I>::EntryDeposit.into();
+			for (who, entry) in Entries::<T, I>::iter() {
+				let held = T::Currency::balance_on_hold(&reason, &who);
+				ensure!(
+					held == Self::entry_deposit(entry.len() as u32),
+					"an entry's deposit does not match the balance held for it"
+				);
+			}
+			Ok(())
+		}
+	}
 }
@@ -0,0 +1,11 @@
+//! Storage migrations of the template pezpallet.
+//!
+//! Each `v*` module migrates the storage from the previous version to the version it is named
+//! after. Add the migration a runtime needs to its `Executive` migrations when upgrading the
+//! pezpallet.
+//!
+//! Stepped migrations, which may take several blocks, do not change the storage version. They are
+//! run by `pezpallet_migrations` and belong in its `Migrations` instead.
+
+pub mod prune;
+pub mod v1;
@@ -0,0 +1,91 @@
+//! Removes the [`StoreCount`] entries of accounts that no longer exist.
+//!
+//! The map holds an entry per account that ever stored a value, so pruning it may not fit in a
+//! single block. [`PruneStoreCounts`] is therefore a stepped migration, to be run through
+//! `pezpallet_migrations` over as many blocks as it needs.
+
+use crate::{Config, Pezpallet, StoreCount, WeightInfo};
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use pezkuwi_sdk::pezsp_runtime::TryRuntimeError;
+use pezkuwi_sdk::{
+	pezframe_support::{
+		migrations::{SteppedMigration, SteppedMigrationError},
+		pezpallet_prelude::*,
+		traits::PalletInfoAccess,
+		weights::WeightMeter,
+	},
+	pezframe_system,
+};
+
+/// The migration-specific part of the identifier of [`PruneStoreCounts`].
+pub const PRUNE_STORE_COUNTS_ID: [u8; 32] = *b"pez-minimal-template-prune-count";
+
+/// Removes the [`StoreCount`] of every account that does not exist anymore from the instance `I`,
+/// one entry per step, resuming from the last account it looked at.
+pub struct PruneStoreCounts<T, I = ()>(core::marker::PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> SteppedMigration for PruneStoreCounts<T, I> {
+	type Cursor = T::AccountId;
+	type Identifier = ([u8; 32], [u8; 16]);
+
+	/// The identifier of the migration in `pezpallet_migrations`: [`PRUNE_STORE_COUNTS_ID`],
+	/// together with the hash of the name of the pezpallet instance it prunes, so that each
+	/// instance is pruned by a migration of its own.
+	fn id() -> Self::Identifier {
+		(PRUNE_STORE_COUNTS_ID, Pezpallet::<T, I>::name_hash())
+	}
+
+	fn step(
+		mut cursor: Option<Self::Cursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+		let required = T::WeightInfo::prune_store_count_step();
+		// Without room for a single entry the migration would never make progress.
+		if meter.remaining().any_lt(required) {
+			return Err(SteppedMigrationError::InsufficientWeight { required });
+		}
+
+		while meter.try_consume(required).is_ok() {
+			let mut iter = match &cursor {
+				Some(last) =>
+					StoreCount::<T, I>::iter_from(StoreCount::<T, I>::hashed_key_for(last)),
+				None => StoreCount::<T, I>::iter(),
+			};
+			let Some((who, _)) = iter.next() else { return Ok(None) };
+
+			if !pezframe_system::Pezpallet::<T>::account_exists(&who) {
+				StoreCount::<T, I>::remove(&who);
+			}
+			cursor = Some(who);
+		}
+
+		Ok(cursor)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		let remaining = StoreCount::<T, I>::iter_keys()
+			.filter(|who| pezframe_system::Pezpallet::<T>::account_exists(who))
+			.count() as u32;
+		Ok(remaining.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let remaining =
+			u32::decode(&mut &state[..]).map_err(|_| "pre_upgrade state does not decode")?;
+
+		ensure!(
+			StoreCount::<T, I>::iter_keys()
+				.all(|who| pezframe_system::Pezpallet::<T>::account_exists(&who)),
+			"a count of a reaped account was kept"
+		);
+		ensure!(
+			StoreCount::<T, I>::iter_keys().count() as u32 == remaining,
+			"a count of an existing account was removed"
+		);
+		Ok(())
+	}
+}
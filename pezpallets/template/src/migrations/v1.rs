@@ -0,0 +1,71 @@
+//! Migrates [`Value`] from a bare `u32` to a [`ValueRecord`].
+
+use crate::{Config, Pezpallet, Value, ValueRecord};
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use pezkuwi_sdk::pezsp_runtime::TryRuntimeError;
+use pezkuwi_sdk::{
+	pezframe_support::{
+		defensive, migrations::VersionedMigration, pezpallet_prelude::*,
+		traits::UncheckedOnRuntimeUpgrade,
+	},
+	pezframe_system,
+};
+
+/// The storage layout of version 0.
+mod v0 {
+	use super::*;
+
+	/// The value, stored without an owner or a block number.
+	#[pezkuwi_sdk::pezframe_support::storage_alias]
+	pub type Value<T: Config<I>, I: 'static> = StorageValue<Pezpallet<T, I>, u32>;
+}
+
+/// Wraps the stored `u32` in a [`ValueRecord`] without an owner, updated in the current block.
+///
+/// Use [`MigrateV0ToV1`] instead, which only runs this while the on-chain storage version is 0
+/// and bumps it afterwards.
+pub struct InnerMigrateV0ToV1<T, I = ()>(core::marker::PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T, I> {
+	fn on_runtime_upgrade() -> Weight {
+		let updated_at = pezframe_system::Pezpallet::<T>::block_number();
+		let translated = Value::<T, I>::translate::<u32, _>(|old| {
+			old.map(|value| ValueRecord { value, owner: None, updated_at })
+		});
+		if translated.is_err() {
+			defensive!("the template value does not decode as a `u32`, it is left untouched");
+		}
+
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		Ok(v0::Value::<T, I>::get().encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let before = Option::<u32>::decode(&mut &state[..])
+			.map_err(|_| "pre_upgrade state does not decode")?;
+		let after = Value::<T, I>::get();
+
+		ensure!(
+			after.as_ref().map(|r| r.value) == before,
+			"the value changed during the migration"
+		);
+		ensure!(after.is_none_or(|r| r.owner.is_none()), "migrated values have no owner");
+		Ok(())
+	}
+}
+
+/// Migrates the instance `I` of the template pezpallet from storage version 0 to 1.
+pub type MigrateV0ToV1<T, I = ()> = VersionedMigration<
+	0,
+	1,
+	InnerMigrateV0ToV1<T, I>,
+	Pezpallet<T, I>,
+	<T as pezframe_system::Config>::DbWeight,
+>;
@@ -0,0 +1,122 @@
+//! Weights for `pezpallet_minimal_template`.
+//!
+//! These are hand-written estimates, not benchmark results. Each is an execution time, the proof
+//! size of the storage the call reads at its maximum encoded length, and the database reads and
+//! writes it makes. Replace them with benchmarked weights before relying on them, by running the
+//! pezpallet's benchmarks on reference hardware:
+//!
+//! ```sh
+//! ./target/release/pez-minimal-template-node benchmark pallet --chain dev \
+//!     --pallet pezpallet_minimal_template --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pezpallets/template/src/weights.rs
+//! ```
+
+use core::marker::PhantomData;
+use pezkuwi_sdk::pezframe_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, RuntimeDbWeight, Weight},
+};
+
+/// Weight functions needed for `pezpallet_minimal_template`.
+pub trait WeightInfo {
+	fn set_value() -> Weight;
+	fn increment() -> Weight;
+	fn clear_value() -> Weight;
+	fn prune_store_count_step() -> Weight;
+	fn force_set_value() -> Weight;
+	fn set_entry(l: u32) -> Weight;
+	fn clear_entry() -> Weight;
+	fn submit_value_unsigned() -> Weight;
+}
+
+/// The proof size of reading [`crate::Value`].
+const VALUE_PROOF: u64 = 1_526;
+/// The proof size of reading an entry of [`crate::StoreCount`].
+const STORE_COUNT_PROOF: u64 = 3_517;
+/// The proof size of reading an account and its holds, as storing or clearing an entry does.
+const ENTRY_DEPOSIT_PROOF: u64 = 3_773;
+/// The proof size of reading an account, as pruning a count does.
+const ACCOUNT_PROOF: u64 = 3_593;
+
+/// Execution taking `ref_time` and reading `proof_size` bytes of proof, plus `reads` and `writes`
+/// priced by `D`.
+fn estimate<D: Get<RuntimeDbWeight>>(
+	ref_time: u64,
+	proof_size: u64,
+	reads: u64,
+	writes: u64,
+) -> Weight {
+	Weight::from_parts(ref_time, proof_size).saturating_add(D::get().reads_writes(reads, writes))
+}
+
+/// Replacing the caller's entry with one of `l` bytes and adjusting its deposit. The execution
+/// time and the proof both grow with the length of the entry.
+fn set_entry<D: Get<RuntimeDbWeight>>(l: u32) -> Weight {
+	estimate::<D>(31_482_000, ENTRY_DEPOSIT_PROOF, 3, 3)
+		.saturating_add(Weight::from_parts(1_935, 1).saturating_mul(l.into()))
+}
+
+/// Estimated weights, with the database accesses priced by the runtime's `DbWeight`.
+pub struct BizinikiwiWeight<T>(PhantomData<T>);
+impl<T: pezkuwi_sdk::pezframe_system::Config> WeightInfo for BizinikiwiWeight<T> {
+	/// Reads the caller's count, writes it and [`crate::Value`].
+	fn set_value() -> Weight {
+		estimate::<T::DbWeight>(8_107_000, STORE_COUNT_PROOF, 1, 2)
+	}
+	/// Reads and writes [`crate::Value`] and the caller's count.
+	fn increment() -> Weight {
+		estimate::<T::DbWeight>(10_268_000, STORE_COUNT_PROOF, 2, 2)
+	}
+	/// Reads and removes [`crate::Value`].
+	fn clear_value() -> Weight {
+		estimate::<T::DbWeight>(6_813_000, VALUE_PROOF, 1, 1)
+	}
+	/// Reads two counts and an account, and removes a count.
+	fn prune_store_count_step() -> Weight {
+		estimate::<T::DbWeight>(9_614_000, ACCOUNT_PROOF, 3, 1)
+	}
+	/// Writes [`crate::Value`].
+	fn force_set_value() -> Weight {
+		estimate::<T::DbWeight>(5_204_000, 0, 0, 1)
+	}
+	/// Reads and writes the caller's entry, holds and account.
+	fn set_entry(l: u32) -> Weight {
+		set_entry::<T::DbWeight>(l)
+	}
+	/// Reads and writes the caller's entry, holds and account.
+	fn clear_entry() -> Weight {
+		estimate::<T::DbWeight>(29_016_000, ENTRY_DEPOSIT_PROOF, 3, 3)
+	}
+	/// Writes [`crate::Value`] and [`crate::NextUnsignedAt`].
+	fn submit_value_unsigned() -> Weight {
+		estimate::<T::DbWeight>(5_871_000, 0, 0, 2)
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn set_value() -> Weight {
+		estimate::<RocksDbWeight>(8_107_000, STORE_COUNT_PROOF, 1, 2)
+	}
+	fn increment() -> Weight {
+		estimate::<RocksDbWeight>(10_268_000, STORE_COUNT_PROOF, 2, 2)
+	}
+	fn clear_value() -> Weight {
+		estimate::<RocksDbWeight>(6_813_000, VALUE_PROOF, 1, 1)
+	}
+	fn prune_store_count_step() -> Weight {
+		estimate::<RocksDbWeight>(9_614_000, ACCOUNT_PROOF, 3, 1)
+	}
+	fn force_set_value() -> Weight {
+		estimate::<RocksDbWeight>(5_204_000, 0, 0, 1)
+	}
+	fn set_entry(l: u32) -> Weight {
+		set_entry::<RocksDbWeight>(l)
+	}
+	fn clear_entry() -> Weight {
+		estimate::<RocksDbWeight>(29_016_000, ENTRY_DEPOSIT_PROOF, 3, 3)
+	}
+	fn submit_value_unsigned() -> Weight {
+		estimate::<RocksDbWeight>(5_871_000, 0, 0, 2)
+	}
+}
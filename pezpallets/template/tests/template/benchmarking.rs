@@ -0,0 +1,8 @@
+//! Runs each benchmark of the template pezpallet once against the mock runtime, so that broken
+//! benchmarks fail `cargo test --features runtime-benchmarks`.
+
+use crate::mock::*;
+use pezkuwi_sdk::pezkuwi_sdk_frame::benchmarking::prelude::*;
+use pezpallet_minimal_template::Pezpallet;
+
+impl_benchmark_test_suite!(Pezpallet, new_test_ext(), Test);
@@ -0,0 +1,118 @@
+use crate::mock::*;
+use pezkuwi_sdk::pezkuwi_sdk_frame::testing_prelude::*;
+use pezpallet_minimal_template::{Error, Event, StoreCount, Value, ValueRecord};
+
+const ALICE: u64 = 1;
+const BOB: u64 = 2;
+
+#[test]
+fn set_value_stores_and_emits() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_value(RuntimeOrigin::signed(ALICE), 42));
+
+		assert_eq!(
+			Value::<Test>::get(),
+			Some(ValueRecord { value: 42, owner: Some(ALICE), updated_at: 1 })
+		);
+		System::assert_last_event(Event::ValueStored { who: ALICE, value: 42 }.into());
+	});
+}
+
+#[test]
+fn set_value_requires_signed_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Template::set_value(RuntimeOrigin::root(), 42), DispatchError::BadOrigin);
+		assert_noop!(Template::set_value(RuntimeOrigin::none(), 42), DispatchError::BadOrigin);
+	});
+}
+
+#[test]
+fn increment_adds_to_stored_value() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_value(RuntimeOrigin::signed(ALICE), 40));
+		System::set_block_number(2);
+		assert_ok!(Template::increment(RuntimeOrigin::signed(BOB), 2));
+
+		assert_eq!(
+			Value::<Test>::get(),
+			Some(ValueRecord { value: 42, owner: Some(BOB), updated_at: 2 })
+		);
+		System::assert_last_event(Event::ValueStored { who: BOB, value: 42 }.into());
+	});
+}
+
+#[test]
+fn increment_fails_without_value() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Template::increment(RuntimeOrigin::signed(ALICE), 1),
+			Error::<Test>::NoneValue
+		);
+	});
+}
+
+#[test]
+fn increment_fails_on_overflow() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_value(RuntimeOrigin::signed(ALICE), u32::MAX));
+		assert_noop!(
+			Template::increment(RuntimeOrigin::signed(ALICE), 1),
+			Error::<Test>::StorageOverflow
+		);
+	});
+}
+
+#[test]
+fn clear_value_removes_value() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_value(RuntimeOrigin::signed(ALICE), 42));
+		assert_ok!(Template::clear_value(RuntimeOrigin::signed(ALICE)));
+
+		assert_eq!(Value::<Test>::get(), None);
+		System::assert_last_event(Event::ValueCleared { who: ALICE }.into());
+	});
+}
+
+#[test]
+fn clear_value_fails_without_value() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Template::clear_value(RuntimeOrigin::signed(ALICE)), Error::<Test>::NoneValue);
+	});
+}
+
+#[test]
+fn storing_counts_per_account() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_value(RuntimeOrigin::signed(ALICE), 40));
+		assert_ok!(Template::increment(RuntimeOrigin::signed(ALICE), 1));
+		assert_ok!(Template::increment(RuntimeOrigin::signed(BOB), 1));
+		assert_ok!(Template::clear_value(RuntimeOrigin::signed(BOB)));
+
+		assert_eq!(StoreCount::<Test>::get(ALICE), 2);
+		assert_eq!(StoreCount::<Test>::get(BOB), 1);
+	});
+}
+
+#[test]
+fn force_set_value_stores_without_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_value(RuntimeOrigin::signed(ALICE), 1));
+		assert_ok!(Template::force_set_value(RuntimeOrigin::root(), 42));
+
+		assert_eq!(
+			Value::<Test>::get(),
+			Some(ValueRecord { value: 42, owner: None, updated_at: 1 })
+		);
+		System::assert_last_event(Event::ValueForced { value: 42 }.into());
+	});
+}
+
+#[test]
+fn force_set_value_requires_admin_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Template::force_set_value(RuntimeOrigin::signed(ALICE), 42),
+			DispatchError::BadOrigin
+		);
+	});
+}
@@ -0,0 +1,130 @@
+use crate::mock::*;
+use pezkuwi_sdk::{
+	pezframe_support::{instances::Instance1, traits::fungible::InspectHold},
+	pezkuwi_sdk_frame::testing_prelude::*,
+};
+use pezpallet_minimal_template::{Entries, Error, Event, HoldReason};
+
+const ALICE: u64 = 1;
+/// An account without any balance.
+const PAUPER: u64 = 5;
+
+fn entry(len: usize) -> BoundedVec<u8, ConstU32<16>> {
+	BoundedVec::try_from(vec![7; len]).unwrap()
+}
+
+fn deposit(len: u64) -> u64 {
+	ENTRY_DEPOSIT_BASE + ENTRY_DEPOSIT_PER_BYTE * len
+}
+
+fn held(who: u64) -> u64 {
+	Balances::balance_on_hold(&RuntimeHoldReason::Template(HoldReason::EntryDeposit), &who)
+}
+
+#[test]
+fn set_entry_stores_it_and_holds_its_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_entry(RuntimeOrigin::signed(ALICE), entry(4)));
+
+		assert_eq!(Entries::<Test>::get(ALICE), Some(entry(4)));
+		assert_eq!(held(ALICE), deposit(4));
+		assert_eq!(Balances::free_balance(ALICE), ENDOWMENT - deposit(4));
+		System::assert_last_event(Event::EntrySet { who: ALICE, deposit: deposit(4) }.into());
+	});
+}
+
+#[test]
+fn replacing_an_entry_adjusts_its_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_entry(RuntimeOrigin::signed(ALICE), entry(4)));
+
+		assert_ok!(Template::set_entry(RuntimeOrigin::signed(ALICE), entry(16)));
+		assert_eq!(held(ALICE), deposit(16));
+		assert_eq!(Balances::free_balance(ALICE), ENDOWMENT - deposit(16));
+
+		assert_ok!(Template::set_entry(RuntimeOrigin::signed(ALICE), entry(0)));
+		assert_eq!(Entries::<Test>::get(ALICE), Some(entry(0)));
+		assert_eq!(held(ALICE), deposit(0));
+		assert_eq!(Balances::free_balance(ALICE), ENDOWMENT - deposit(0));
+	});
+}
+
+#[test]
+fn clear_entry_releases_the_whole_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_entry(RuntimeOrigin::signed(ALICE), entry(4)));
+		assert_ok!(Template::set_entry(RuntimeOrigin::signed(ALICE), entry(9)));
+
+		assert_ok!(Template::clear_entry(RuntimeOrigin::signed(ALICE)));
+
+		assert_eq!(Entries::<Test>::get(ALICE), None);
+		assert_eq!(held(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), ENDOWMENT);
+		System::assert_last_event(Event::EntryCleared { who: ALICE, deposit: deposit(9) }.into());
+	});
+}
+
+#[test]
+fn clear_entry_fails_without_entry() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Template::clear_entry(RuntimeOrigin::signed(ALICE)), Error::<Test>::NoEntry);
+	});
+}
+
+#[test]
+fn set_entry_fails_without_funds_for_the_deposit() {
+	new_test_ext().execute_with(|| {
+		assert!(Template::set_entry(RuntimeOrigin::signed(PAUPER), entry(4)).is_err());
+		assert_eq!(Entries::<Test>::get(PAUPER), None);
+	});
+}
+
+#[test]
+fn set_entry_requires_signed_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Template::set_entry(RuntimeOrigin::root(), entry(4)),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn each_instance_holds_its_own_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_entry(RuntimeOrigin::signed(ALICE), entry(4)));
+		assert_ok!(StagingTemplate::set_entry(RuntimeOrigin::signed(ALICE), entry(8)));
+
+		assert_ok!(Template::clear_entry(RuntimeOrigin::signed(ALICE)));
+
+		assert_eq!(held(ALICE), 0);
+		assert_eq!(
+			Balances::balance_on_hold(
+				&RuntimeHoldReason::StagingTemplate(HoldReason::<Instance1>::EntryDeposit),
+				&ALICE
+			),
+			deposit(8)
+		);
+		assert_eq!(Entries::<Test, Instance1>::get(ALICE), Some(entry(8)));
+		assert_eq!(Balances::free_balance(ALICE), ENDOWMENT - deposit(8));
+	});
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn try_state_checks_that_each_entry_holds_its_deposit() {
+	use pezkuwi_sdk::pezframe_support::traits::{fungible::MutateHold, tokens::Precision};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_entry(RuntimeOrigin::signed(ALICE), entry(4)));
+		assert_ok!(Template::do_try_state());
+
+		assert_ok!(Balances::release(
+			&RuntimeHoldReason::Template(HoldReason::EntryDeposit),
+			&ALICE,
+			1,
+			Precision::Exact
+		));
+		assert!(Template::do_try_state().is_err());
+	});
+}
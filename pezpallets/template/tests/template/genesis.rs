@@ -0,0 +1,30 @@
+use crate::mock::*;
+use pezkuwi_sdk::pezkuwi_sdk_frame::testing_prelude::*;
+use pezpallet_minimal_template::{Value, ValueRecord};
+
+fn ext_with_genesis_value(value: Option<u32>) -> TestState {
+	RuntimeGenesisConfig {
+		template: TemplateConfig { value, ..Default::default() },
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap()
+	.into()
+}
+
+#[test]
+fn genesis_value_is_stored() {
+	ext_with_genesis_value(Some(7)).execute_with(|| {
+		assert_eq!(
+			Value::<Test>::get(),
+			Some(ValueRecord { value: 7, owner: None, updated_at: 0 })
+		);
+	});
+}
+
+#[test]
+fn no_genesis_value_leaves_storage_empty() {
+	ext_with_genesis_value(None).execute_with(|| {
+		assert_eq!(Value::<Test>::get(), None);
+	});
+}
@@ -0,0 +1,74 @@
+use crate::mock::*;
+use codec::{Decode, Encode};
+use pezkuwi_sdk::{
+	pezframe_support::{instances::Instance1, view_functions::ViewFunction},
+	pezkuwi_sdk_frame::testing_prelude::*,
+};
+use pezpallet_minimal_template::{Event, GetValueViewFunction, StoreCount, Value};
+
+const ALICE: u64 = 1;
+
+#[test]
+fn instances_store_values_independently() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_value(RuntimeOrigin::signed(ALICE), 1));
+		assert_eq!(Value::<Test, Instance1>::get(), None);
+		assert_eq!(StoreCount::<Test, Instance1>::get(ALICE), 0);
+
+		assert_ok!(StagingTemplate::set_value(RuntimeOrigin::signed(ALICE), 2));
+		assert_ok!(StagingTemplate::increment(RuntimeOrigin::signed(ALICE), 1));
+		assert_eq!(Value::<Test>::get().map(|r| r.value), Some(1));
+		assert_eq!(Value::<Test, Instance1>::get().map(|r| r.value), Some(3));
+		assert_eq!(StoreCount::<Test>::get(ALICE), 1);
+		assert_eq!(StoreCount::<Test, Instance1>::get(ALICE), 2);
+
+		assert_ok!(Template::clear_value(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(Value::<Test>::get(), None);
+		assert_eq!(Value::<Test, Instance1>::get().map(|r| r.value), Some(3));
+	});
+}
+
+#[test]
+fn instances_emit_their_own_events() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(StagingTemplate::set_value(RuntimeOrigin::signed(ALICE), 2));
+
+		System::assert_last_event(RuntimeEvent::StagingTemplate(
+			Event::<Test, Instance1>::ValueStored { who: ALICE, value: 2 },
+		));
+		assert!(!System::events().iter().any(|r| matches!(r.event, RuntimeEvent::Template(_))));
+	});
+}
+
+#[test]
+fn instances_have_their_own_genesis_value() {
+	let mut ext: TestState = RuntimeGenesisConfig {
+		template: TemplateConfig { value: Some(1), ..Default::default() },
+		staging_template: StagingTemplateConfig { value: Some(2), ..Default::default() },
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap()
+	.into();
+
+	ext.execute_with(|| {
+		assert_eq!(Value::<Test>::get().map(|r| r.value), Some(1));
+		assert_eq!(Value::<Test, Instance1>::get().map(|r| r.value), Some(2));
+	});
+}
+
+#[test]
+fn view_functions_of_each_instance_read_its_own_value() {
+	fn get_value<Q: ViewFunction + Encode>(query: Q) -> Option<u32> {
+		let output = Test::execute_view_function(Q::id(), query.encode()).unwrap();
+		Option::<u32>::decode(&mut &output[..]).unwrap()
+	}
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_value(RuntimeOrigin::signed(ALICE), 1));
+		assert_ok!(StagingTemplate::set_value(RuntimeOrigin::signed(ALICE), 2));
+
+		assert_eq!(get_value(GetValueViewFunction::<Test>::new()), Some(1));
+		assert_eq!(get_value(GetValueViewFunction::<Test, Instance1>::new()), Some(2));
+	});
+}
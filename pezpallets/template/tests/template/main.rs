@@ -0,0 +1,12 @@
+//! Tests for the template pezpallet, run against the mock runtime in [`mock`].
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod calls;
+mod entries;
+mod genesis;
+mod instances;
+mod migrations;
+mod mock;
+mod offchain;
+mod view_functions;
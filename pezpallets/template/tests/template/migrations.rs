@@ -0,0 +1,131 @@
+use crate::mock::*;
+use pezkuwi_sdk::{
+	pezframe_support::{
+		migrations::{SteppedMigration, SteppedMigrationError},
+		storage::unhashed,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		weights::{Weight, WeightMeter},
+	},
+	pezkuwi_sdk_frame::testing_prelude::*,
+};
+use pezpallet_minimal_template::{
+	migrations::{prune::PruneStoreCounts, v1::MigrateV0ToV1},
+	StoreCount, Value, ValueRecord, WeightInfo,
+};
+
+/// Store `value` in the version 0 layout, a bare `u32`, and mark the storage as version 0.
+fn put_v0_value(value: u32) {
+	unhashed::put(&Value::<Test>::hashed_key(), &value);
+	StorageVersion::new(0).put::<Template>();
+}
+
+#[test]
+fn v0_value_becomes_a_record_without_owner() {
+	new_test_ext().execute_with(|| {
+		put_v0_value(7);
+		System::set_block_number(5);
+
+		MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			Value::<Test>::get(),
+			Some(ValueRecord { value: 7, owner: None, updated_at: 5 })
+		);
+		assert_eq!(Template::on_chain_storage_version(), StorageVersion::new(1));
+	});
+}
+
+#[test]
+fn missing_v0_value_stays_missing() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Template>();
+
+		MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Value::<Test>::get(), None);
+		assert_eq!(Template::on_chain_storage_version(), StorageVersion::new(1));
+	});
+}
+
+#[test]
+fn migration_is_skipped_at_version_1() {
+	new_test_ext().execute_with(|| {
+		let record = ValueRecord { value: 7, owner: Some(1), updated_at: 1 };
+		Value::<Test>::put(record.clone());
+		StorageVersion::new(1).put::<Template>();
+
+		MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Value::<Test>::get(), Some(record));
+	});
+}
+
+/// Give `existing` accounts a provider and a count, and `reaped` accounts only a count.
+fn put_store_counts(
+	existing: impl IntoIterator<Item = u64>,
+	reaped: impl IntoIterator<Item = u64>,
+) {
+	for who in existing {
+		System::inc_providers(&who);
+		StoreCount::<Test>::insert(who, 1);
+	}
+	for who in reaped {
+		StoreCount::<Test>::insert(who, 1);
+	}
+}
+
+/// Run [`PruneStoreCounts`] to completion with `meter_limit` per step, returning the steps taken.
+fn prune_to_completion(meter_limit: Weight) -> u32 {
+	let mut cursor = None;
+	let mut steps = 0;
+	loop {
+		cursor = PruneStoreCounts::<Test>::step(cursor, &mut WeightMeter::with_limit(meter_limit))
+			.unwrap();
+		steps += 1;
+		if cursor.is_none() {
+			return steps;
+		}
+	}
+}
+
+#[test]
+fn prune_removes_counts_of_reaped_accounts_only() {
+	new_test_ext().execute_with(|| {
+		put_store_counts(1..=3, 10..=15);
+
+		prune_to_completion(Weight::MAX);
+
+		let mut kept = StoreCount::<Test>::iter_keys().collect::<Vec<_>>();
+		kept.sort();
+		assert_eq!(kept, vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn prune_stays_within_the_weight_of_each_step() {
+	new_test_ext().execute_with(|| {
+		put_store_counts(1..=4, 10..=15);
+		let per_entry = <() as WeightInfo>::prune_store_count_step();
+
+		// Room for three entries per step: ten entries and the final empty lookup take four.
+		assert_eq!(prune_to_completion(per_entry.saturating_mul(3)), 4);
+		assert_eq!(StoreCount::<Test>::iter_keys().count(), 4);
+	});
+}
+
+#[test]
+fn prune_fails_without_weight_for_a_single_entry() {
+	new_test_ext().execute_with(|| {
+		put_store_counts([], [10]);
+		let required = <() as WeightInfo>::prune_store_count_step();
+
+		assert_eq!(
+			PruneStoreCounts::<Test>::step(
+				None,
+				&mut WeightMeter::with_limit(required.saturating_sub(Weight::from_parts(1, 0)))
+			),
+			Err(SteppedMigrationError::InsufficientWeight { required })
+		);
+		assert!(StoreCount::<Test>::contains_key(10));
+	});
+}
@@ -0,0 +1,151 @@
+//! A mock runtime containing only `System`, `Balances` and two instances of the template
+//! pezpallet, whose offchain workers sign with [`UintAuthorityId`] keys.
+
+use pezkuwi_sdk::{
+	pezframe_support::instances::Instance1,
+	pezframe_system::offchain::{
+		AppCrypto, CreateBare, CreateSignedTransaction, CreateTransaction, CreateTransactionBase,
+		SigningTypes,
+	},
+	pezkuwi_sdk_frame::testing_prelude::*,
+	pezsp_runtime::testing::{TestSignature, UintAuthorityId},
+	*,
+};
+
+type Block = MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test {
+		System: pezframe_system,
+		Balances: pezpallet_balances,
+		Template: pezpallet_minimal_template,
+		StagingTemplate: pezpallet_minimal_template::<Instance1>,
+	}
+);
+
+#[derive_impl(pezframe_system::config_preludes::TestDefaultConfig)]
+impl pezframe_system::Config for Test {
+	type Block = Block;
+	type AccountData = pezpallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pezpallet_balances::config_preludes::TestDefaultConfig)]
+impl pezpallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+/// The extrinsics the offchain worker submits. Signed ones name the signing account but carry no
+/// signature.
+pub type Extrinsic = MockUncheckedExtrinsic<Test>;
+
+impl SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<C> CreateTransactionBase<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type Extrinsic = Extrinsic;
+	type RuntimeCall = RuntimeCall;
+}
+
+impl<C> CreateBare<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	fn create_bare(call: RuntimeCall) -> Extrinsic {
+		Extrinsic::new_bare(call)
+	}
+}
+
+impl<C> CreateTransaction<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type Extension = ();
+
+	fn create_transaction(call: RuntimeCall, extension: ()) -> Extrinsic {
+		Extrinsic::new_transaction(call, extension)
+	}
+}
+
+impl<C> CreateSignedTransaction<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	fn create_signed_transaction<A: AppCrypto<UintAuthorityId, TestSignature>>(
+		call: RuntimeCall,
+		_public: UintAuthorityId,
+		account: u64,
+		_nonce: u64,
+	) -> Option<Extrinsic> {
+		Some(Extrinsic::new_signed(call, account, (), ()))
+	}
+}
+
+/// Signs as the account whose id is the [`UintAuthorityId`] of a key set with
+/// [`UintAuthorityId::set_all_keys`].
+pub struct TestAuthId;
+
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
+/// The priority of unsigned submissions.
+pub const UNSIGNED_PRIORITY: u64 = 100;
+/// How many blocks must pass between two unsigned submissions.
+pub const UNSIGNED_INTERVAL: u64 = 5;
+
+/// The deposit held for an entry, whatever its length.
+pub const ENTRY_DEPOSIT_BASE: u64 = 10;
+/// The deposit held for each byte of an entry.
+pub const ENTRY_DEPOSIT_PER_BYTE: u64 = 2;
+/// What every account holds at genesis.
+pub const ENDOWMENT: u64 = 100;
+
+impl pezpallet_minimal_template::Config for Test {
+	type AdminOrigin = pezframe_system::EnsureRoot<u64>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type MaxEntryLen = ConstU32<16>;
+	type EntryDepositBase = ConstU64<ENTRY_DEPOSIT_BASE>;
+	type EntryDepositPerByte = ConstU64<ENTRY_DEPOSIT_PER_BYTE>;
+	type AuthorityId = TestAuthId;
+	type UnsignedPriority = ConstU64<UNSIGNED_PRIORITY>;
+	type UnsignedInterval = ConstU64<UNSIGNED_INTERVAL>;
+	type WeightInfo = ();
+}
+
+impl pezpallet_minimal_template::Config<Instance1> for Test {
+	type AdminOrigin = pezframe_system::EnsureRoot<u64>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type MaxEntryLen = ConstU32<16>;
+	type EntryDepositBase = ConstU64<ENTRY_DEPOSIT_BASE>;
+	type EntryDepositPerByte = ConstU64<ENTRY_DEPOSIT_PER_BYTE>;
+	type AuthorityId = TestAuthId;
+	type UnsignedPriority = ConstU64<UNSIGNED_PRIORITY>;
+	type UnsignedInterval = ConstU64<UNSIGNED_INTERVAL>;
+	type WeightInfo = ();
+}
+
+/// Build test externalities in which accounts 1 to 4 hold [`ENDOWMENT`] and nothing else is
+/// stored, starting at block 1 so events are recorded.
+pub fn new_test_ext() -> TestState {
+	let mut ext: TestState = RuntimeGenesisConfig {
+		balances: pezpallet_balances::GenesisConfig {
+			balances: (1..=4).map(|who| (who, ENDOWMENT)).collect(),
+			..Default::default()
+		},
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap()
+	.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
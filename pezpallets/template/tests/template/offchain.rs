@@ -0,0 +1,210 @@
+use crate::mock::*;
+use codec::Decode;
+use pezkuwi_sdk::{
+	pezframe_support::traits::Hooks,
+	pezkuwi_sdk_frame::testing_prelude::*,
+	pezsp_core::offchain::{
+		testing::{TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	},
+	pezsp_runtime::{
+		testing::UintAuthorityId,
+		traits::ValidateUnsigned,
+		transaction_validity::{
+			InvalidTransaction, TransactionSource, TransactionValidity, ValidTransaction,
+		},
+	},
+};
+use pezpallet_minimal_template::{Call, Event, NextUnsignedAt, Value, ValueRecord};
+
+const ALICE: u64 = 1;
+
+/// Run `test` in [`new_test_ext`] with an offchain worker and a transaction pool, and return
+/// the extrinsics it submitted to the pool.
+fn submitted_by(test: impl FnOnce()) -> Vec<Extrinsic> {
+	let (offchain, _) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(test);
+
+	let transactions = pool_state.read().transactions.clone();
+	transactions.iter().map(|tx| Extrinsic::decode(&mut &tx[..]).unwrap()).collect()
+}
+
+/// Run the offchain worker of the default instance on top of block `n`.
+fn offchain_worker(n: u64) {
+	<Template as Hooks<u64>>::offchain_worker(n)
+}
+
+/// Validate `call` as the pool would an unsigned extrinsic received from the network.
+fn validate(call: Call<Test>) -> TransactionValidity {
+	Template::validate_unsigned(TransactionSource::External, &call)
+}
+
+#[test]
+fn offchain_worker_submits_next_value_unsigned() {
+	let submitted = submitted_by(|| {
+		assert_ok!(Template::set_value(RuntimeOrigin::signed(ALICE), 41));
+		offchain_worker(1);
+	});
+
+	let call = Call::submit_value_unsigned { block_number: 1, value: 42 };
+	assert_eq!(submitted, vec![Extrinsic::new_bare(call.into())]);
+}
+
+#[test]
+fn offchain_worker_submits_zero_without_value() {
+	let submitted = submitted_by(|| offchain_worker(1));
+
+	let call = Call::submit_value_unsigned { block_number: 1, value: 0 };
+	assert_eq!(submitted, vec![Extrinsic::new_bare(call.into())]);
+}
+
+#[test]
+fn offchain_worker_waits_for_unsigned_interval() {
+	let submitted = submitted_by(|| {
+		assert_ok!(Template::submit_value_unsigned(RuntimeOrigin::none(), 1, 0));
+		offchain_worker(1 + UNSIGNED_INTERVAL - 1);
+	});
+
+	assert_eq!(submitted, vec![]);
+
+	let submitted = submitted_by(|| {
+		assert_ok!(Template::submit_value_unsigned(RuntimeOrigin::none(), 1, 0));
+		offchain_worker(1 + UNSIGNED_INTERVAL);
+	});
+
+	let call = Call::submit_value_unsigned { block_number: 1 + UNSIGNED_INTERVAL, value: 1 };
+	assert_eq!(submitted, vec![Extrinsic::new_bare(call.into())]);
+}
+
+#[test]
+fn offchain_worker_signs_with_local_key() {
+	let submitted = submitted_by(|| {
+		UintAuthorityId::set_all_keys([ALICE]);
+		assert_ok!(Template::set_value(RuntimeOrigin::signed(ALICE), 41));
+		// Signed submissions do not wait for the next unsigned one.
+		NextUnsignedAt::<Test>::put(10);
+		offchain_worker(1);
+	});
+
+	let call = Call::set_value { value: 42 };
+	assert_eq!(submitted, vec![Extrinsic::new_signed(call.into(), ALICE, (), ())]);
+}
+
+#[test]
+fn offchain_worker_signs_at_most_once_per_interval() {
+	let submitted = submitted_by(|| {
+		UintAuthorityId::set_all_keys([ALICE]);
+		offchain_worker(1);
+		offchain_worker(1 + UNSIGNED_INTERVAL - 1);
+		offchain_worker(1 + UNSIGNED_INTERVAL);
+	});
+
+	let xt = Extrinsic::new_signed(Call::set_value { value: 0 }.into(), ALICE, (), ());
+	assert_eq!(submitted, vec![xt.clone(), xt]);
+}
+
+#[test]
+fn submit_value_unsigned_stores_without_owner() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		assert_ok!(Template::submit_value_unsigned(RuntimeOrigin::none(), 2, 42));
+
+		assert_eq!(
+			Value::<Test>::get(),
+			Some(ValueRecord { value: 42, owner: None, updated_at: 3 })
+		);
+		assert_eq!(NextUnsignedAt::<Test>::get(), 2 + UNSIGNED_INTERVAL);
+		System::assert_last_event(Event::ValueSubmitted { value: 42 }.into());
+	});
+}
+
+#[test]
+fn submit_value_unsigned_requires_none_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Template::submit_value_unsigned(RuntimeOrigin::signed(ALICE), 1, 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Template::submit_value_unsigned(RuntimeOrigin::root(), 1, 0),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_accepts_next_value() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_value(RuntimeOrigin::signed(ALICE), 41));
+
+		assert_eq!(
+			validate(Call::submit_value_unsigned { block_number: 1, value: 42 }),
+			ValidTransaction::with_tag_prefix("Template")
+				.priority(UNSIGNED_PRIORITY)
+				.and_provides(0u64)
+				.longevity(UNSIGNED_INTERVAL)
+				.propagate(true)
+				.build()
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_provides_one_tag_per_interval() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		let provides = |block_number, value| {
+			validate(Call::submit_value_unsigned { block_number, value }).unwrap().provides
+		};
+
+		// Submissions computed on different blocks of the same interval replace each other.
+		let first = provides(1, 0);
+		assert_eq!(provides(3, 0), first);
+
+		assert_ok!(Template::submit_value_unsigned(RuntimeOrigin::none(), 3, 0));
+		System::set_block_number(3 + UNSIGNED_INTERVAL);
+		assert_ne!(provides(3 + UNSIGNED_INTERVAL, 1), first);
+	});
+}
+
+#[test]
+fn validate_unsigned_rejects_stale_future_and_wrong_values() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(2);
+		NextUnsignedAt::<Test>::put(2);
+
+		assert_eq!(
+			validate(Call::submit_value_unsigned { block_number: 1, value: 0 }),
+			InvalidTransaction::Stale.into()
+		);
+		assert_eq!(
+			validate(Call::submit_value_unsigned { block_number: 3, value: 0 }),
+			InvalidTransaction::Future.into()
+		);
+		assert_eq!(
+			validate(Call::submit_value_unsigned { block_number: 2, value: 1 }),
+			InvalidTransaction::Call.into()
+		);
+		assert_eq!(validate(Call::set_value { value: 0 }), InvalidTransaction::Call.into());
+	});
+}
+
+#[test]
+fn validate_unsigned_tags_differ_per_instance() {
+	new_test_ext().execute_with(|| {
+		let default = validate(Call::submit_value_unsigned { block_number: 1, value: 0 }).unwrap();
+		let staging = StagingTemplate::validate_unsigned(
+			TransactionSource::External,
+			&Call::submit_value_unsigned { block_number: 1, value: 0 },
+		)
+		.unwrap();
+
+		assert_ne!(default.provides, staging.provides);
+	});
+}
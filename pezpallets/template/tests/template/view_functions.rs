@@ -0,0 +1,54 @@
+use crate::mock::*;
+use codec::{Decode, Encode};
+use pezkuwi_sdk::{
+	pezframe_support::view_functions::ViewFunction, pezkuwi_sdk_frame::testing_prelude::*,
+};
+use pezpallet_minimal_template::{
+	GetStoreCountViewFunction, GetValueRecordViewFunction, GetValueViewFunction, ValueRecord,
+};
+
+/// Dispatch `query` through the runtime like a front end would and decode its result.
+fn dispatch<Q: ViewFunction + Encode, V: Decode>(query: Q) -> V {
+	let output = Test::execute_view_function(Q::id(), query.encode()).unwrap();
+	V::decode(&mut &output[..]).unwrap()
+}
+
+#[test]
+fn view_functions_read_nothing_without_value() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Template::get_value(), None);
+		assert_eq!(dispatch::<_, Option<u32>>(GetValueViewFunction::<Test>::new()), None);
+		assert_eq!(
+			dispatch::<_, Option<ValueRecord<u64, u64>>>(GetValueRecordViewFunction::<Test>::new()),
+			None
+		);
+	});
+}
+
+#[test]
+fn view_functions_read_the_stored_value() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_value(RuntimeOrigin::signed(1), 42));
+
+		let record = ValueRecord { value: 42, owner: Some(1), updated_at: 1 };
+		assert_eq!(Template::get_value(), Some(42));
+		assert_eq!(Template::get_value_record(), Some(record.clone()));
+		assert_eq!(dispatch::<_, Option<u32>>(GetValueViewFunction::<Test>::new()), Some(42));
+		assert_eq!(
+			dispatch::<_, Option<ValueRecord<u64, u64>>>(GetValueRecordViewFunction::<Test>::new()),
+			Some(record)
+		);
+	});
+}
+
+#[test]
+fn store_count_view_function_reads_per_account() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_value(RuntimeOrigin::signed(1), 1));
+		assert_ok!(Template::set_value(RuntimeOrigin::signed(1), 2));
+
+		assert_eq!(Template::get_store_count(1), 2);
+		assert_eq!(dispatch::<_, u32>(GetStoreCountViewFunction::<Test>::new(1)), 2);
+		assert_eq!(dispatch::<_, u32>(GetStoreCountViewFunction::<Test>::new(2)), 0);
+	});
+}
@@ -1,8 +1,122 @@
 //! Build script for pez-minimal-template-runtime.
 
+/// The file holding the `spec_version` of the last released runtime.
+const RELEASED_SPEC_VERSION_FILE: &str = "released-spec-version";
+
+/// The environment variable that overrides [`RELEASED_SPEC_VERSION_FILE`], e.g. with the
+/// `spec_version` of the runtime that is live on a particular chain.
+const PREVIOUS_SPEC_VERSION_ENV: &str = "RUNTIME_PREVIOUS_SPEC_VERSION";
+
 fn main() {
 	#[cfg(feature = "std")]
 	{
 		pezkuwi_sdk::bizinikiwi_wasm_builder::WasmBuilder::build_using_defaults();
+		#[cfg(feature = "spec-version-incremented-wasm")]
+		pezkuwi_sdk::bizinikiwi_wasm_builder::WasmBuilder::init_with_defaults()
+			.enable_feature("increment-spec-version")
+			.set_file_name("wasm_binary_spec_version_incremented.rs")
+			.build();
+		check_spec_version();
+	}
+}
+
+/// Refuse to build a runtime whose `spec_version` is not greater than the previous one, as
+/// `set_code` would reject it on chain anyway.
+///
+/// The `spec_version` is read from the `runtime_version` section of the WASM binary that was
+/// just built, and compared against [`PREVIOUS_SPEC_VERSION_ENV`] if it is set and against the
+/// last release in [`RELEASED_SPEC_VERSION_FILE`] otherwise.
+#[cfg(feature = "std")]
+fn check_spec_version() {
+	println!("cargo:rerun-if-env-changed={PREVIOUS_SPEC_VERSION_ENV}");
+	println!("cargo:rerun-if-changed={RELEASED_SPEC_VERSION_FILE}");
+
+	let (previous, source) = match std::env::var(PREVIOUS_SPEC_VERSION_ENV) {
+		Ok(previous) => (previous, PREVIOUS_SPEC_VERSION_ENV),
+		Err(_) => (
+			std::fs::read_to_string(RELEASED_SPEC_VERSION_FILE)
+				.unwrap_or_else(|e| panic!("{RELEASED_SPEC_VERSION_FILE} is not readable: {e}")),
+			RELEASED_SPEC_VERSION_FILE,
+		),
+	};
+	let previous: u32 = previous
+		.trim()
+		.parse()
+		.unwrap_or_else(|_| panic!("{source} does not hold a number: `{previous}`"));
+
+	let Some(wasm) = built_wasm() else {
+		println!(
+			"cargo:warning=The WASM binary was not built, so its `spec_version` is not checked"
+		);
+		return;
+	};
+	let current = spec_version(&wasm)
+		.expect("the WASM binary has a `runtime_version` section starting with `spec_version`");
+
+	assert!(
+		current > previous,
+		"`spec_version` is {current}, but must be greater than the previous {previous} from \
+		 {source}. Bump it in `VERSION` before releasing the runtime."
+	);
+}
+
+/// The WASM binary the WASM builder just built, with its custom sections, if it built one.
+#[cfg(feature = "std")]
+fn built_wasm() -> Option<Vec<u8>> {
+	let out_dir = std::env::var("OUT_DIR").expect("cargo sets `OUT_DIR`; qed");
+	let generated = std::fs::read_to_string(std::path::Path::new(&out_dir).join("wasm_binary.rs"))
+		.expect("the WASM builder writes `wasm_binary.rs`");
+
+	// `WASM_BINARY_BLOATY` is the binary before it is compacted and compressed.
+	let line = generated.lines().find(|line| line.contains("WASM_BINARY_BLOATY"))?;
+	let path = line.split("include_bytes!(\"").nth(1)?.split("\")").next()?;
+	Some(std::fs::read(path).expect("the WASM builder wrote the binary it points to"))
+}
+
+/// The `spec_version` in the `runtime_version` custom section of `wasm`.
+#[cfg(feature = "std")]
+fn spec_version(wasm: &[u8]) -> Option<u32> {
+	use codec::Decode;
+
+	let section = custom_section(wasm, "runtime_version")?;
+	// `RuntimeVersion` starts with `spec_name`, `impl_name`, `authoring_version` and
+	// `spec_version`.
+	let (_, _, _, spec_version) = <(String, String, u32, u32)>::decode(&mut &section[..]).ok()?;
+	Some(spec_version)
+}
+
+/// The contents of the custom section `name` of the WASM module `wasm`.
+#[cfg(feature = "std")]
+fn custom_section<'a>(wasm: &'a [u8], name: &str) -> Option<&'a [u8]> {
+	/// Read an unsigned LEB128 integer from the start of `bytes`, advancing past it.
+	fn leb128(bytes: &mut &[u8]) -> Option<usize> {
+		let mut value = 0usize;
+		for shift in (0..35).step_by(7) {
+			let (&byte, rest) = bytes.split_first()?;
+			*bytes = rest;
+			value |= ((byte & 0x7f) as usize) << shift;
+			if byte & 0x80 == 0 {
+				return Some(value);
+			}
+		}
+		None
+	}
+
+	// Skip the magic number and the version.
+	let mut rest = wasm.strip_prefix(b"\0asm")?.get(4..)?;
+	while let Some((&id, after_id)) = rest.split_first() {
+		rest = after_id;
+		let len = leb128(&mut rest)?;
+		let mut contents = rest.get(..len)?;
+		rest = &rest[len..];
+
+		// Custom sections have the id 0 and start with their name.
+		if id == 0 {
+			let name_len = leb128(&mut contents)?;
+			if contents.get(..name_len)? == name.as_bytes() {
+				return Some(&contents[name_len..]);
+			}
+		}
 	}
+	None
 }
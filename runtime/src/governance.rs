@@ -0,0 +1,179 @@
+//! On-chain governance, which replaces `Sudo` when the `governance` feature is enabled.
+//!
+//! Privileged calls, such as `System::set_code` or `Template::force_set_value`, are reached
+//! through a referendum on the [`ROOT_TRACK`], voted on with conviction by token holders. The
+//! council can cancel referenda, and a majority of it can also act as the template pezpallet's
+//! admin origin.
+
+use super::*;
+use alloc::borrow::Cow;
+use interface::{AccountId, Balance};
+use pezframe_support::traits::{
+	fungible::HoldConsideration, tokens::currency::ActiveIssuanceOf, EitherOfDiverse,
+	EqualPrivilegeOnly, LinearStoragePrice, OriginTrait,
+};
+use pezframe_system::{EnsureRoot, EnsureSigned};
+use pezpallet_referenda::{str_array as s, Curve, Track, TrackInfo};
+use pezsp_runtime::Perbill;
+
+type BlockNumber = pezframe_system::pezpallet_prelude::BlockNumberFor<Runtime>;
+
+/// A minute, in blocks.
+pub const MINUTES: BlockNumber = (60_000 / MILLI_SECS_PER_BLOCK) as BlockNumber;
+/// An hour, in blocks.
+pub const HOURS: BlockNumber = 60 * MINUTES;
+/// A day, in blocks.
+pub const DAYS: BlockNumber = 24 * HOURS;
+
+/// The instance of the collective pezpallet used as the council.
+pub type CouncilCollective = pezpallet_collective::Instance1;
+
+/// At least half of the council.
+pub type EnsureCouncilMajority =
+	pezpallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
+
+/// Root, reached through a referendum, or a majority of the council.
+pub type EnsureRootOrCouncilMajority =
+	EitherOfDiverse<EnsureRoot<AccountId>, EnsureCouncilMajority>;
+
+parameter_types! {
+	/// Scheduled calls may use up to 80% of each block.
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
+		<Runtime as frame_system::Config>::BlockWeights::get().max_block;
+	/// Council proposals may use up to half of a block.
+	pub MaxCouncilProposalWeight: Weight = Perbill::from_percent(50) *
+		<Runtime as frame_system::Config>::BlockWeights::get().max_block;
+	pub const CouncilMotionDuration: BlockNumber = 3 * DAYS;
+	pub const CouncilMaxMembers: u32 = 100;
+	pub const PreimageHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::Preimage(pezpallet_preimage::HoldReason::Preimage);
+	pub const PreimageBaseDeposit: Balance = deposit(2, 64);
+	pub const PreimageByteDeposit: Balance = deposit(0, 1);
+	pub const VoteLockingPeriod: BlockNumber = 7 * DAYS;
+	pub const SubmissionDeposit: Balance = 10 * UNIT;
+	pub const UndecidingTimeout: BlockNumber = 14 * DAYS;
+}
+
+// Implements the types required for the preimage pezpallet.
+impl pezpallet_preimage::Config for Runtime {
+	type WeightInfo = pezpallet_preimage::weights::BizinikiwiWeight<Runtime>;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type Consideration = HoldConsideration<
+		AccountId,
+		Balances,
+		PreimageHoldReason,
+		LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+	>;
+}
+
+// Implements the types required for the scheduler pezpallet.
+impl pezpallet_scheduler::Config for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type MaxScheduledPerBlock = ConstU32<50>;
+	type WeightInfo = pezpallet_scheduler::weights::BizinikiwiWeight<Runtime>;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type Preimages = Preimage;
+	type BlockNumberProvider = System;
+}
+
+// Implements the types required for the council.
+impl pezpallet_collective::Config<CouncilCollective> for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type MotionDuration = CouncilMotionDuration;
+	type MaxProposals = ConstU32<100>;
+	type MaxMembers = CouncilMaxMembers;
+	type DefaultVote = pezpallet_collective::PrimeDefaultVote;
+	type WeightInfo = pezpallet_collective::weights::BizinikiwiWeight<Runtime>;
+	// Council membership can only be changed by a referendum.
+	type SetMembersOrigin = EnsureRoot<AccountId>;
+	type MaxProposalWeight = MaxCouncilProposalWeight;
+	type DisapproveOrigin = EnsureRoot<AccountId>;
+	type KillOrigin = EnsureRoot<AccountId>;
+	type Consideration = ();
+}
+
+// Implements the types required for the conviction voting pezpallet.
+impl pezpallet_conviction_voting::Config for Runtime {
+	type WeightInfo = pezpallet_conviction_voting::weights::BizinikiwiWeight<Runtime>;
+	type Currency = Balances;
+	type VoteLockingPeriod = VoteLockingPeriod;
+	type MaxVotes = ConstU32<512>;
+	type MaxTurnout = ActiveIssuanceOf<Balances, AccountId>;
+	type Polls = Referenda;
+	type BlockNumberProvider = System;
+	type VotingHooks = ();
+}
+
+/// The id of the track referenda dispatching as root are decided on.
+pub const ROOT_TRACK: u16 = 0;
+
+/// The tracks referenda can be submitted on.
+const TRACKS: [Track<u16, Balance, BlockNumber>; 1] = [Track {
+	id: ROOT_TRACK,
+	info: TrackInfo {
+		name: s("root"),
+		max_deciding: 1,
+		decision_deposit: 1_000 * UNIT,
+		prepare_period: 2 * HOURS,
+		decision_period: 7 * DAYS,
+		confirm_period: DAYS,
+		min_enactment_period: DAYS,
+		// Approval falls from 100% to 50% and support from 50% to 0% over the decision period.
+		min_approval: Curve::LinearDecreasing {
+			length: Perbill::from_percent(100),
+			floor: Perbill::from_percent(50),
+			ceil: Perbill::from_percent(100),
+		},
+		min_support: Curve::LinearDecreasing {
+			length: Perbill::from_percent(100),
+			floor: Perbill::from_percent(0),
+			ceil: Perbill::from_percent(50),
+		},
+	},
+}];
+
+/// Maps the origins referenda dispatch as to their [`TRACKS`].
+pub struct TracksInfo;
+impl pezpallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
+	type Id = u16;
+	type RuntimeOrigin = <RuntimeOrigin as OriginTrait>::PalletsOrigin;
+
+	fn tracks() -> impl Iterator<Item = Cow<'static, Track<Self::Id, Balance, BlockNumber>>> {
+		TRACKS.iter().map(Cow::Borrowed)
+	}
+
+	fn track_for(origin: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
+		match frame_system::RawOrigin::try_from(origin.clone()) {
+			Ok(frame_system::RawOrigin::Root) => Ok(ROOT_TRACK),
+			_ => Err(()),
+		}
+	}
+}
+
+// Implements the types required for the referenda pezpallet.
+impl pezpallet_referenda::Config for Runtime {
+	type WeightInfo = pezpallet_referenda::weights::BizinikiwiWeight<Runtime>;
+	type RuntimeCall = RuntimeCall;
+	type Scheduler = Scheduler;
+	type Currency = Balances;
+	type SubmitOrigin = EnsureSigned<AccountId>;
+	type CancelOrigin = EnsureRootOrCouncilMajority;
+	type KillOrigin = EnsureRoot<AccountId>;
+	// Slashed deposits are burned.
+	type Slash = ();
+	type Votes = pezpallet_conviction_voting::VotesOf<Runtime>;
+	type Tally = pezpallet_conviction_voting::TallyOf<Runtime>;
+	type SubmissionDeposit = SubmissionDeposit;
+	type MaxQueued = ConstU32<100>;
+	type UndecidingTimeout = UndecidingTimeout;
+	type AlarmInterval = ConstU32<1>;
+	type Tracks = TracksInfo;
+	type Preimages = Preimage;
+	type BlockNumberProvider = System;
+}
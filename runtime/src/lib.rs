@@ -6,6 +6,13 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+/// The WASM binary of this runtime built with `increment-spec-version`, which runtime upgrade
+/// tests can set as the new code.
+#[cfg(all(feature = "std", feature = "spec-version-incremented-wasm"))]
+pub mod wasm_spec_version_incremented {
+	include!(concat!(env!("OUT_DIR"), "/wasm_binary_spec_version_incremented.rs"));
+}
+
 extern crate alloc;
 
 use alloc::vec::Vec;
@@ -17,39 +24,183 @@ use pezkuwi_sdk::{
 		runtime::{apis, prelude::*},
 	},
 	pezframe_system as frame_system,
+	pezsp_consensus_aura::sr25519::AuthorityId as AuraId,
+	pezsp_runtime::{
+		impl_opaque_keys,
+		traits::{ConvertInto, NumberFor, OpaqueKeys},
+	},
 	*,
 };
 
 /// Provides getters for genesis configuration presets.
 pub mod genesis_config_presets {
 	use super::*;
+	#[cfg(feature = "governance")]
+	use crate::runtime::CouncilConfig;
+	#[cfg(not(feature = "governance"))]
+	use crate::runtime::SudoConfig;
 	use crate::{
-		interface::{Balance, MinimumBalance},
-		runtime::{BalancesConfig, RuntimeGenesisConfig, SudoConfig},
+		interface::{AssetId, Balance, MinimumBalance},
+		opaque::SessionKeys,
+		runtime::{
+			AssetsConfig, BalancesConfig, RuntimeGenesisConfig, SessionConfig, TemplateConfig,
+		},
 	};
 	#[cfg(feature = "std")]
-	use pezkuwi_sdk::pezsp_keyring::Sr25519Keyring;
+	use pezkuwi_sdk::pezsp_keyring::{Ed25519Keyring, Sr25519Keyring};
 
 	use alloc::{vec, vec::Vec};
 	use serde_json::Value;
 
-	/// Returns a development genesis config preset.
-	pub fn development_config_genesis() -> Value {
-		let endowment = <MinimumBalance as Get<Balance>>::get().max(1) * 1000;
+	/// The name of the staging genesis config preset.
+	pub const STAGING_RUNTIME_PRESET: &str = "staging";
+
+	/// The id of the sufficient test asset every preset creates, owned by the root account.
+	pub const TEST_ASSET_ID: AssetId = 1;
+
+	/// How many units of the test asset a unit of the native token is worth, which is the rate
+	/// fees paid in the test asset are converted at.
+	pub const TEST_ASSET_UNITS_PER_NATIVE_UNIT: Balance = 2;
+
+	/// The part of the genesis config patch that lets `root` dispatch privileged calls, by making
+	/// it the sudo key.
+	#[cfg(not(feature = "governance"))]
+	fn root_genesis(root: Sr25519Keyring) -> Value {
 		pezframe_support::build_struct_json_patch!(RuntimeGenesisConfig {
+			sudo: SudoConfig { key: Some(root.to_account_id()) },
+		})
+	}
+
+	/// The part of the genesis config patch that lets `root` take part in governance, by making
+	/// it the only council member. Privileged calls need a referendum.
+	#[cfg(feature = "governance")]
+	fn root_genesis(root: Sr25519Keyring) -> Value {
+		pezframe_support::build_struct_json_patch!(RuntimeGenesisConfig {
+			council: CouncilConfig { members: vec![root.to_account_id()] },
+		})
+	}
+
+	/// Build a genesis config patch for the given authorities, endowed accounts, root account and
+	/// initial template value.
+	///
+	/// Each authority is given as its Aura (sr25519) and GRANDPA (ed25519) key. See
+	/// [`root_genesis`] for the role of `root`. The endowed accounts also receive `endowment` of
+	/// the [`TEST_ASSET_ID`] asset, and the treasury is endowed with the existential deposit.
+	fn testnet_genesis(
+		authorities: Vec<(Sr25519Keyring, Ed25519Keyring)>,
+		endowed_accounts: Vec<Sr25519Keyring>,
+		endowment: Balance,
+		root: Sr25519Keyring,
+		template_value: Option<u32>,
+	) -> Value {
+		let mut patch = pezframe_support::build_struct_json_patch!(RuntimeGenesisConfig {
 			balances: BalancesConfig {
-				balances: Sr25519Keyring::iter()
+				balances: endowed_accounts
+					.iter()
 					.map(|a| (a.to_account_id(), endowment))
+					// The treasury must exist for dust and fees paid into it not to be burned.
+					.chain([(crate::fees::TreasuryAccount::get(), MinimumBalance::get())])
 					.collect::<Vec<_>>(),
 			},
-			sudo: SudoConfig { key: Some(Sr25519Keyring::Alice.to_account_id()) },
-		})
+			// The session pezpallet hands the keys to Aura and GRANDPA at genesis, so their
+			// authorities are not set directly.
+			session: SessionConfig {
+				keys: authorities
+					.iter()
+					.map(|(aura, grandpa)| {
+						(
+							aura.to_account_id(),
+							aura.to_account_id(),
+							SessionKeys {
+								aura: aura.public().into(),
+								grandpa: grandpa.public().into(),
+							},
+						)
+					})
+					.collect::<Vec<_>>(),
+			},
+			template: TemplateConfig { value: template_value },
+			// Only sufficient assets can pay fees, and its minimum balance sets their rate.
+			assets: AssetsConfig {
+				assets: vec![(
+					TEST_ASSET_ID,
+					root.to_account_id(),
+					true,
+					TEST_ASSET_UNITS_PER_NATIVE_UNIT * MinimumBalance::get(),
+				)],
+				metadata: vec![(TEST_ASSET_ID, b"Test".to_vec(), b"TEST".to_vec(), 12)],
+				accounts: endowed_accounts
+					.iter()
+					.map(|a| (TEST_ASSET_ID, a.to_account_id(), endowment))
+					.collect::<Vec<_>>(),
+			},
+		});
+		if let (Value::Object(patch), Value::Object(root)) = (&mut patch, root_genesis(root)) {
+			patch.extend(root);
+		}
+		patch
+	}
+
+	/// Returns a development genesis config preset.
+	///
+	/// Alice is the only authority and the root account, every well-known account is endowed and
+	/// the template value starts at 42.
+	pub fn development_config_genesis() -> Value {
+		testnet_genesis(
+			vec![(Sr25519Keyring::Alice, Ed25519Keyring::Alice)],
+			Sr25519Keyring::iter().collect(),
+			1_000_000 * UNIT,
+			Sr25519Keyring::Alice,
+			Some(42),
+		)
+	}
+
+	/// Returns a local testnet genesis config preset.
+	///
+	/// Alice and Bob are the authorities, Alice is the root account, and every well-known account
+	/// is endowed.
+	pub fn local_config_genesis() -> Value {
+		testnet_genesis(
+			vec![
+				(Sr25519Keyring::Alice, Ed25519Keyring::Alice),
+				(Sr25519Keyring::Bob, Ed25519Keyring::Bob),
+			],
+			Sr25519Keyring::iter().collect(),
+			100_000 * UNIT,
+			Sr25519Keyring::Alice,
+			None,
+		)
+	}
+
+	/// Returns a staging genesis config preset.
+	///
+	/// Alice, Bob and Charlie are the authorities and Dave is the root account. Only these
+	/// accounts are endowed.
+	pub fn staging_config_genesis() -> Value {
+		testnet_genesis(
+			vec![
+				(Sr25519Keyring::Alice, Ed25519Keyring::Alice),
+				(Sr25519Keyring::Bob, Ed25519Keyring::Bob),
+				(Sr25519Keyring::Charlie, Ed25519Keyring::Charlie),
+			],
+			vec![
+				Sr25519Keyring::Alice,
+				Sr25519Keyring::Bob,
+				Sr25519Keyring::Charlie,
+				Sr25519Keyring::Dave,
+			],
+			10_000 * UNIT,
+			Sr25519Keyring::Dave,
+			None,
+		)
 	}
 
 	/// Get the set of the available genesis config presets.
 	pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
 		let patch = match id.as_ref() {
 			pezsp_genesis_builder::DEV_RUNTIME_PRESET => development_config_genesis(),
+			pezsp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET => local_config_genesis(),
+			STAGING_RUNTIME_PRESET => staging_config_genesis(),
 			_ => return None,
 		};
 		Some(
@@ -61,23 +212,68 @@ pub mod genesis_config_presets {
 
 	/// List of supported presets.
 	pub fn preset_names() -> Vec<PresetId> {
-		vec![PresetId::from(pezsp_genesis_builder::DEV_RUNTIME_PRESET)]
+		vec![
+			PresetId::from(pezsp_genesis_builder::DEV_RUNTIME_PRESET),
+			PresetId::from(pezsp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
+			PresetId::from(STAGING_RUNTIME_PRESET),
+		]
 	}
 }
 
 /// The runtime version.
+#[cfg(not(feature = "increment-spec-version"))]
 #[runtime_version]
 pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: alloc::borrow::Cow::Borrowed("pez-minimal-template-runtime"),
 	impl_name: alloc::borrow::Cow::Borrowed("pez-minimal-template-runtime"),
 	authoring_version: 1,
-	spec_version: 0,
+	spec_version: 1,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
 	system_version: 1,
 };
 
+/// The runtime version, with `spec_version` one above the released runtime's, for runtime
+/// upgrade tests.
+#[cfg(feature = "increment-spec-version")]
+#[runtime_version]
+pub const VERSION: RuntimeVersion = RuntimeVersion {
+	spec_name: alloc::borrow::Cow::Borrowed("pez-minimal-template-runtime"),
+	impl_name: alloc::borrow::Cow::Borrowed("pez-minimal-template-runtime"),
+	authoring_version: 1,
+	spec_version: 2,
+	impl_version: 1,
+	apis: RUNTIME_API_VERSIONS,
+	transaction_version: 1,
+	system_version: 1,
+};
+
+/// The block time the Aura slot duration is derived from, in milliseconds.
+pub const MILLI_SECS_PER_BLOCK: u64 = 3000;
+
+/// The Aura slot duration, in milliseconds.
+///
+/// Changing this after the chain has started will break block production.
+pub const SLOT_DURATION: u64 = MILLI_SECS_PER_BLOCK;
+
+/// One unit of the native token, which has 12 decimals.
+pub const UNIT: interface::Balance = 1_000_000_000_000;
+/// A thousandth of a [`UNIT`].
+pub const MILLI_UNIT: interface::Balance = UNIT / 1_000;
+/// A millionth of a [`UNIT`].
+pub const MICRO_UNIT: interface::Balance = UNIT / 1_000_000;
+
+/// The SS58 address format of accounts on this chain, whose native token is `PEZ`.
+pub const SS58_PREFIX: u16 = 1453;
+
+/// The deposit for keeping `items` storage items of `bytes` bytes in total in state.
+///
+/// Used by the pezpallets that let accounts put data in storage, so that it is paid for.
+pub const fn deposit(items: u32, bytes: u32) -> interface::Balance {
+	items as interface::Balance * 100 * MILLI_UNIT + bytes as interface::Balance * MILLI_UNIT
+}
+
 /// The version information used to identify this runtime when compiled natively.
 #[cfg(feature = "std")]
 pub fn native_version() -> NativeVersion {
@@ -85,7 +281,7 @@ pub fn native_version() -> NativeVersion {
 }
 
 /// The transaction extensions that are added to the runtime.
-type TxExtension = (
+pub type TxExtension = (
 	// Checks that the sender is not the zero address.
 	frame_system::CheckNonZeroSender<Runtime>,
 	// Checks that the runtime version is correct.
@@ -101,8 +297,9 @@ type TxExtension = (
 	// Checks that the weight is valid.
 	frame_system::CheckWeight<Runtime>,
 	// Ensures that the sender has enough funds to pay for the transaction
-	// and deducts the fee from the sender's account.
-	pezpallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	// and deducts the fee from the sender's account, in the native token or in
+	// the sufficient asset the sender chose.
+	pezpallet_asset_tx_payment::ChargeAssetTxPayment<Runtime>,
 	// Reclaim the unused weight from the block using post dispatch information.
 	// It must be last in the pipeline in order to catch the refund in previous transaction
 	// extensions
@@ -143,7 +340,9 @@ pub mod runtime {
 	#[runtime::pezpallet_index(2)]
 	pub type Balances = pezpallet_balances::Pezpallet<Runtime>;
 
-	/// Provides a way to execute privileged functions.
+	/// Provides a way to execute privileged functions. Replaced by on-chain governance with the
+	/// `governance` feature.
+	#[cfg(not(feature = "governance"))]
 	#[runtime::pezpallet_index(3)]
 	pub type Sudo = pezpallet_sudo::Pezpallet<Runtime>;
 
@@ -154,17 +353,139 @@ pub mod runtime {
 	/// A minimal pezpallet template.
 	#[runtime::pezpallet_index(5)]
 	pub type Template = pezpallet_minimal_template::Pezpallet<Runtime>;
+
+	/// A second instance of the template pezpallet, holding a staging value independent of
+	/// [`Template`]'s.
+	#[runtime::pezpallet_index(20)]
+	pub type StagingTemplate =
+		pezpallet_minimal_template::Pezpallet<Runtime, pezframe_support::instances::Instance1>;
+
+	/// Manages the session keys of the authorities. Must come before `Aura` and `Grandpa` so
+	/// that session changes are applied before they run.
+	#[runtime::pezpallet_index(8)]
+	pub type Session = pezpallet_session::Pezpallet<Runtime>;
+
+	/// Provides slot-based block authoring.
+	#[runtime::pezpallet_index(6)]
+	pub type Aura = pezpallet_aura::Pezpallet<Runtime>;
+
+	/// Provides GRANDPA block finality.
+	#[runtime::pezpallet_index(7)]
+	pub type Grandpa = pezpallet_grandpa::Pezpallet<Runtime>;
+
+	/// Runs migrations that take more than one block, holding back transactions until they are
+	/// done.
+	#[runtime::pezpallet_index(9)]
+	pub type MultiBlockMigrations = pezpallet_migrations::Pezpallet<Runtime>;
+
+	/// Provides batching of calls and dispatching them as a derivative account.
+	#[runtime::pezpallet_index(10)]
+	pub type Utility = pezpallet_utility::Pezpallet<Runtime>;
+
+	/// Allows accounts to delegate the dispatch of some of their calls to other accounts.
+	#[runtime::pezpallet_index(11)]
+	pub type Proxy = pezpallet_proxy::Pezpallet<Runtime>;
+
+	/// Provides accounts controlled by a threshold of several signatories.
+	#[runtime::pezpallet_index(12)]
+	pub type Multisig = pezpallet_multisig::Pezpallet<Runtime>;
+
+	/// Stores the calls referenda and the scheduler dispatch.
+	#[cfg(feature = "governance")]
+	#[runtime::pezpallet_index(13)]
+	pub type Preimage = pezpallet_preimage::Pezpallet<Runtime>;
+
+	/// Dispatches calls at a later block, e.g. enacted referenda.
+	#[cfg(feature = "governance")]
+	#[runtime::pezpallet_index(14)]
+	pub type Scheduler = pezpallet_scheduler::Pezpallet<Runtime>;
+
+	/// The council, which can cancel referenda and administer the template pezpallet.
+	#[cfg(feature = "governance")]
+	#[runtime::pezpallet_index(15)]
+	pub type Council = pezpallet_collective::Pezpallet<Runtime, pezpallet_collective::Instance1>;
+
+	/// Lets token holders vote on referenda, weighting their votes by how long they lock.
+	#[cfg(feature = "governance")]
+	#[runtime::pezpallet_index(16)]
+	pub type ConvictionVoting = pezpallet_conviction_voting::Pezpallet<Runtime>;
+
+	/// Decides on proposals dispatched as root by referendum.
+	#[cfg(feature = "governance")]
+	#[runtime::pezpallet_index(17)]
+	pub type Referenda = pezpallet_referenda::Pezpallet<Runtime>;
+
+	/// Provides fungible assets besides the native token.
+	#[runtime::pezpallet_index(18)]
+	pub type Assets = pezpallet_assets::Pezpallet<Runtime>;
+
+	/// Lets transaction fees be paid in sufficient assets.
+	#[runtime::pezpallet_index(19)]
+	pub type AssetTxPayment = pezpallet_asset_tx_payment::Pezpallet<Runtime>;
 }
 
 pub use runtime::{
-	Runtime, System, Timestamp, Balances, Sudo, TransactionPayment, Template,
+	Runtime, System, Timestamp, Balances, TransactionPayment, Template, StagingTemplate, Session,
+	Aura, Grandpa,
+	MultiBlockMigrations, Utility, Proxy, Multisig, Assets, AssetTxPayment,
 	RuntimeCall, RuntimeEvent, RuntimeError, RuntimeOrigin, RuntimeFreezeReason,
 	RuntimeHoldReason, RuntimeSlashReason, RuntimeLockId, RuntimeTask, RuntimeViewFunction,
-	AllPalletsWithSystem, RuntimeGenesisConfig, BalancesConfig, SudoConfig,
+	OriginCaller, AllPalletsWithSystem, RuntimeGenesisConfig, BalancesConfig, AuraConfig,
+	GrandpaConfig, TemplateConfig, StagingTemplateConfig, SessionConfig, AssetsConfig,
 };
+#[cfg(not(feature = "governance"))]
+pub use runtime::{Sudo, SudoConfig};
+#[cfg(feature = "governance")]
+pub use runtime::{Preimage, Scheduler, Council, ConvictionVoting, Referenda, CouncilConfig};
+
+/// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
+/// the specifics of the runtime.
+pub mod opaque {
+	use super::*;
+
+	impl_opaque_keys! {
+		/// The keys a validator needs to author and finalize blocks.
+		pub struct SessionKeys {
+			pub aura: Aura,
+			pub grandpa: Grandpa,
+		}
+	}
+}
+
+/// The share of each block's weight and length `Normal` extrinsics may use. The rest is kept
+/// for `Operational` ones, so that e.g. governance can act on a congested chain.
+const NORMAL_DISPATCH_RATIO: pezsp_runtime::Perbill = pezsp_runtime::Perbill::from_percent(75);
+
+/// A block may take up to a third of the block time to execute. There is no proof size limit,
+/// as blocks are not validated by a relay chain.
+const MAXIMUM_BLOCK_WEIGHT: pezframe_support::weights::Weight =
+	pezframe_support::weights::Weight::from_parts(
+		pezframe_support::weights::constants::WEIGHT_REF_TIME_PER_SECOND * MILLI_SECS_PER_BLOCK /
+			1_000 / 3,
+		u64::MAX,
+	);
+
+/// A block may be up to 5 MiB long.
+const MAXIMUM_BLOCK_LENGTH: u32 = 5 * 1024 * 1024;
 
 parameter_types! {
 	pub const Version: RuntimeVersion = VERSION;
+	/// The weight limits of a block and of each dispatch class in it.
+	pub RuntimeBlockWeights: frame_system::limits::BlockWeights =
+		frame_system::limits::BlockWeights::with_sensible_defaults(
+			MAXIMUM_BLOCK_WEIGHT,
+			NORMAL_DISPATCH_RATIO,
+		);
+	/// The length limits of a block and of each dispatch class in it.
+	pub RuntimeBlockLength: frame_system::limits::BlockLength =
+		frame_system::limits::BlockLength::max_with_normal_ratio(
+			MAXIMUM_BLOCK_LENGTH,
+			NORMAL_DISPATCH_RATIO,
+		);
+	pub const MaxAuthorities: u32 = 32;
+	/// Sessions last ten minutes.
+	pub const SessionPeriod: u32 = (10 * 60 * 1000 / MILLI_SECS_PER_BLOCK) as u32;
+	pub const SessionOffset: u32 = 0;
 }
 
 /// Implements the types required for the system pezpallet.
@@ -172,42 +493,627 @@ parameter_types! {
 impl frame_system::Config for Runtime {
 	type Block = Block;
 	type Version = Version;
+	type BlockWeights = RuntimeBlockWeights;
+	type BlockLength = RuntimeBlockLength;
+	type DbWeight = pezframe_support::weights::constants::RocksDbWeight;
+	type SS58Prefix = ConstU16<SS58_PREFIX>;
+	// Accounts may be depended on by at most this many pezpallets, e.g. by holding their assets.
+	type MaxConsumers = ConstU32<16>;
+	// Multi-block migrations are stepped by the migrations pezpallet.
+	type MultiBlockMigrator = MultiBlockMigrations;
 	// Use the account data from the balances pezpallet
 	type AccountData = pezpallet_balances::AccountData<<Runtime as pezpallet_balances::Config>::Balance>;
 }
 
+parameter_types! {
+	/// Accounts whose total balance falls below this are reaped.
+	pub const ExistentialDeposit: u128 = MILLI_UNIT;
+}
+
 // Implements the types required for the balances pezpallet.
-#[derive_impl(pezpallet_balances::config_preludes::TestDefaultConfig)]
 impl pezpallet_balances::Config for Runtime {
+	type Balance = u128;
+	type ExistentialDeposit = ExistentialDeposit;
+	// The dust left by reaped accounts goes to the treasury, like fees.
+	type DustRemoval =
+		pezframe_support::traits::tokens::imbalance::ResolveTo<fees::TreasuryAccount, Balances>;
 	type AccountStore = System;
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type FreezeIdentifier = RuntimeFreezeReason;
+	// Holds are bounded by the number of `RuntimeHoldReason` variants, and freezes likewise.
+	type MaxFreezes = pezframe_support::traits::VariantCountOf<RuntimeFreezeReason>;
+	type DoneSlashHandler = ();
+	type WeightInfo = pezpallet_balances::weights::BizinikiwiWeight<Runtime>;
 }
 
 // Implements the types required for the sudo pezpallet.
+#[cfg(not(feature = "governance"))]
 #[derive_impl(pezpallet_sudo::config_preludes::TestDefaultConfig)]
 impl pezpallet_sudo::Config for Runtime {}
 
 // Implements the types required for the timestamp pezpallet.
 #[derive_impl(pezpallet_timestamp::config_preludes::TestDefaultConfig)]
-impl pezpallet_timestamp::Config for Runtime {}
+impl pezpallet_timestamp::Config for Runtime {
+	// Aura checks that the timestamp of each block matches its slot.
+	type OnTimestampSet = Aura;
+	type MinimumPeriod = ConstU64<{ SLOT_DURATION / 2 }>;
+}
+
+// Implements the types required for the session pezpallet.
+impl pezpallet_session::Config for Runtime {
+	// Validators are identified by their account.
+	type ValidatorId = <Self as frame_system::Config>::AccountId;
+	type ValidatorIdOf = ConvertInto;
+	type ShouldEndSession = pezpallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+	type NextSessionRotation = pezpallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+	// The set of validators only changes through their keys, not through a staking system.
+	type SessionManager = ();
+	type SessionHandler = <opaque::SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
+	type Keys = opaque::SessionKeys;
+	type DisablingStrategy = ();
+	type WeightInfo = pezpallet_session::weights::BizinikiwiWeight<Runtime>;
+	type Currency = Balances;
+	type KeyDeposit = ();
+}
+
+// Implements the types required for the aura pezpallet.
+impl pezpallet_aura::Config for Runtime {
+	type AuthorityId = AuraId;
+	type DisabledValidators = ();
+	type MaxAuthorities = MaxAuthorities;
+	type AllowMultipleBlocksPerSlot = ConstBool<false>;
+	type SlotDuration = ConstU64<SLOT_DURATION>;
+}
+
+// Implements the types required for the grandpa pezpallet.
+impl pezpallet_grandpa::Config for Runtime {
+	type WeightInfo = ();
+	type MaxAuthorities = MaxAuthorities;
+	type MaxNominators = ConstU32<0>;
+	type MaxSetIdSessionEntries = ConstU64<0>;
+	// Equivocation reporting is not supported.
+	type KeyOwnerProof = pezsp_core::Void;
+	type EquivocationReportSystem = ();
+}
+
+// Implements the types required for the utility pezpallet.
+impl pezpallet_utility::Config for Runtime {
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = pezpallet_utility::weights::BizinikiwiWeight<Runtime>;
+}
+
+/// The kinds of calls a proxy may make on behalf of the account that added it.
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Default,
+	codec::Encode,
+	codec::Decode,
+	codec::DecodeWithMemTracking,
+	codec::MaxEncodedLen,
+	scale_info::TypeInfo,
+	pezsp_runtime::RuntimeDebug,
+)]
+pub enum ProxyType {
+	/// Any call.
+	#[default]
+	Any,
+	/// Calls of the balances pezpallet, e.g. for a hot key that only moves funds.
+	Balances,
+	/// Calls of either instance of the template pezpallet.
+	Template,
+}
+
+impl pezframe_support::traits::InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, call: &RuntimeCall) -> bool {
+		match self {
+			ProxyType::Any => true,
+			// Batches are allowed, as the filter also applies to each call they dispatch.
+			ProxyType::Balances => {
+				matches!(call, RuntimeCall::Balances(..) | RuntimeCall::Utility(..))
+			},
+			ProxyType::Template => {
+				matches!(
+					call,
+					RuntimeCall::Template(..) |
+						RuntimeCall::StagingTemplate(..) |
+						RuntimeCall::Utility(..)
+				)
+			},
+		}
+	}
+
+	fn is_superset(&self, other: &Self) -> bool {
+		self == other || *self == ProxyType::Any
+	}
+}
+
+parameter_types! {
+	/// A proxy definition: the key and the `Vec` holding the proxies.
+	pub const ProxyDepositBase: interface::Balance = deposit(1, 8);
+	/// Each proxy: an account, a `ProxyType` and a delay.
+	pub const ProxyDepositFactor: interface::Balance = deposit(0, 33);
+	/// An announcement: the key and the `Vec` holding the announcements.
+	pub const AnnouncementDepositBase: interface::Balance = deposit(1, 8);
+	/// Each announcement: an account, a call hash and a block number.
+	pub const AnnouncementDepositFactor: interface::Balance = deposit(0, 68);
+	/// A pending multisig operation: its key and its timepoint, deposit and depositor.
+	pub const MultisigDepositBase: interface::Balance = deposit(1, 88);
+	/// Each approval of a pending multisig operation.
+	pub const MultisigDepositFactor: interface::Balance = deposit(0, 32);
+}
+
+// Implements the types required for the proxy pezpallet.
+impl pezpallet_proxy::Config for Runtime {
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ProxyDepositBase;
+	type ProxyDepositFactor = ProxyDepositFactor;
+	type MaxProxies = ConstU32<32>;
+	type WeightInfo = pezpallet_proxy::weights::BizinikiwiWeight<Runtime>;
+	type MaxPending = ConstU32<32>;
+	type CallHasher = pezsp_runtime::traits::BlakeTwo256;
+	type AnnouncementDepositBase = AnnouncementDepositBase;
+	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type BlockNumberProvider = System;
+}
+
+// Implements the types required for the multisig pezpallet.
+impl pezpallet_multisig::Config for Runtime {
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type DepositBase = MultisigDepositBase;
+	type DepositFactor = MultisigDepositFactor;
+	type MaxSignatories = ConstU32<100>;
+	type WeightInfo = pezpallet_multisig::weights::BizinikiwiWeight<Runtime>;
+	type BlockNumberProvider = System;
+}
+
+#[cfg(feature = "governance")]
+pub mod governance;
+
+/// The fee model used by the transaction payment pezpallet.
+pub mod fees {
+	use super::*;
+	use pezframe_support::{
+		traits::{
+			fungible::{Balanced, Credit},
+			fungibles, Imbalance, OnUnbalanced,
+		},
+		weights::{
+			constants::ExtrinsicBaseWeight, WeightToFeeCoefficient, WeightToFeeCoefficients,
+			WeightToFeePolynomial,
+		},
+		PalletId,
+	};
+	use pezpallet_asset_tx_payment::HandleCredit;
+	use pezpallet_transaction_payment::Multiplier;
+	use pezsp_runtime::{
+		traits::{AccountIdConversion, Bounded},
+		FixedPointNumber, Perbill, Perquintill,
+	};
+	use smallvec::smallvec;
+
+	parameter_types! {
+		/// The fee charged per byte of an extrinsic.
+		pub const TransactionByteFee: interface::Balance = 10 * MICRO_UNIT;
+		/// The block fullness the fee multiplier tries to keep blocks at.
+		pub const TargetBlockFullness: Perquintill = Perquintill::from_percent(25);
+		/// How fast the fee multiplier reacts to blocks above or below the target fullness.
+		pub AdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(3, 100_000);
+		/// The fee multiplier never goes below this value.
+		pub MinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 1_000_000_000u128);
+		/// The fee multiplier never goes above this value.
+		pub MaximumMultiplier: Multiplier = Bounded::max_value();
+		/// The account fees and tips are paid into.
+		pub TreasuryAccount: interface::AccountId = PalletId(*b"py/trsry").into_account_truncating();
+	}
+
+	/// Charges [`MILLI_UNIT`] / 10 for the weight of a base extrinsic and scales linearly with the
+	/// `ref_time` of the weight from there.
+	pub struct WeightToFee;
+	impl WeightToFeePolynomial for WeightToFee {
+		type Balance = interface::Balance;
+		fn polynomial() -> WeightToFeeCoefficients<Self::Balance> {
+			let p = MILLI_UNIT / 10;
+			let q = Self::Balance::from(ExtrinsicBaseWeight::get().ref_time());
+			smallvec![WeightToFeeCoefficient {
+				degree: 1,
+				negative: false,
+				coeff_frac: Perbill::from_rational(p % q, q),
+				coeff_integer: p / q,
+			}]
+		}
+	}
+
+	/// Burns 20% of every fee and pays the rest of the fee, as well as the whole tip, to
+	/// [`TreasuryAccount`].
+	pub struct DealWithFees;
+	impl OnUnbalanced<Credit<interface::AccountId, Balances>> for DealWithFees {
+		fn on_unbalanceds(
+			mut fees_then_tips: impl Iterator<Item = Credit<interface::AccountId, Balances>>,
+		) {
+			if let Some(fees) = fees_then_tips.next() {
+				// Dropping a credit reduces the total issuance, which burns it.
+				let (mut to_treasury, _burned) = fees.ration(80, 20);
+				if let Some(tips) = fees_then_tips.next() {
+					tips.merge_into(&mut to_treasury);
+				}
+				// Anything the treasury account can't take, e.g. because it would stay below the
+				// existential deposit, is burned as well.
+				let _ = Balances::resolve(&TreasuryAccount::get(), to_treasury);
+			}
+		}
+	}
+
+	/// Pays the whole of every fee paid in an asset, tip included, to [`TreasuryAccount`].
+	pub struct AssetFeesToTreasury;
+	impl HandleCredit<interface::AccountId, Assets> for AssetFeesToTreasury {
+		fn handle_credit(credit: fungibles::Credit<interface::AccountId, Assets>) {
+			// As with native fees, anything the treasury account can't take is burned.
+			let _ = <Assets as fungibles::Balanced<_>>::resolve(&TreasuryAccount::get(), credit);
+		}
+	}
+
+	/// Creates and funds the asset the asset transaction payment benchmarks pay fees in.
+	#[cfg(feature = "runtime-benchmarks")]
+	pub struct AssetTxPaymentBenchmarkHelper;
+	#[cfg(feature = "runtime-benchmarks")]
+	impl
+		pezpallet_asset_tx_payment::BenchmarkHelperTrait<
+			interface::AccountId,
+			interface::AssetId,
+			interface::AssetId,
+		> for AssetTxPaymentBenchmarkHelper
+	{
+		fn create_asset_id_parameter(id: u32) -> (interface::AssetId, interface::AssetId) {
+			(id, id)
+		}
+
+		fn setup_balances_and_pool(asset_id: interface::AssetId, account: interface::AccountId) {
+			use pezframe_support::traits::fungibles::Mutate;
+			Assets::force_create(RuntimeOrigin::root(), asset_id, account.clone().into(), true, 1)
+				.expect("the asset does not exist yet; qed");
+			Assets::mint_into(asset_id, &account, 1_000 * UNIT)
+				.expect("the asset was just created; qed");
+		}
+	}
+}
 
 // Implements the types required for the transaction payment pezpallet.
 #[derive_impl(pezpallet_transaction_payment::config_preludes::TestDefaultConfig)]
 impl pezpallet_transaction_payment::Config for Runtime {
-	type OnChargeTransaction = pezpallet_transaction_payment::FungibleAdapter<Balances, ()>;
-	// Setting fee as independent of the weight of the extrinsic for demo purposes
-	type WeightToFee = NoFee<<Self as pezpallet_balances::Config>::Balance>;
-	// Setting fee as fixed for any length of the call data for demo purposes
-	type LengthToFee = FixedFee<1, <Self as pezpallet_balances::Config>::Balance>;
+	type OnChargeTransaction =
+		pezpallet_transaction_payment::FungibleAdapter<Balances, fees::DealWithFees>;
+	type WeightToFee = fees::WeightToFee;
+	type LengthToFee =
+		pezframe_support::weights::ConstantMultiplier<interface::Balance, fees::TransactionByteFee>;
+	type FeeMultiplierUpdate = pezpallet_transaction_payment::TargetedFeeAdjustment<
+		Self,
+		fees::TargetBlockFullness,
+		fees::AdjustmentVariable,
+		fees::MinimumMultiplier,
+		fees::MaximumMultiplier,
+	>;
+	// Operational extrinsics pay five times the fee of a normal one with the same tip.
+	type OperationalFeeMultiplier = ConstU8<5>;
+}
+
+parameter_types! {
+	/// An asset: its details, and its key in the map holding them.
+	pub const AssetDeposit: interface::Balance = deposit(1, 210);
+	/// An account holding a non-sufficient asset: its balance and its key.
+	pub const AssetAccountDeposit: interface::Balance = deposit(1, 16);
+	/// The metadata of an asset: its key, decimals and frozen flag.
+	pub const AssetMetadataDepositBase: interface::Balance = deposit(1, 68);
+	/// Each byte of the name and symbol of an asset.
+	pub const AssetMetadataDepositPerByte: interface::Balance = deposit(0, 1);
+	/// An approval to spend an asset: its amount and its key.
+	pub const AssetApprovalDeposit: interface::Balance = deposit(1, 72);
+}
+
+// Implements the types required for the assets pezpallet.
+#[derive_impl(pezpallet_assets::config_preludes::TestDefaultConfig)]
+impl pezpallet_assets::Config for Runtime {
+	type Balance = interface::Balance;
+	type Currency = Balances;
+	type CreateOrigin = pezframe_support::traits::AsEnsureOriginWithArg<
+		frame_system::EnsureSigned<interface::AccountId>,
+	>;
+	type ForceOrigin = frame_system::EnsureRoot<interface::AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = AssetMetadataDepositBase;
+	type MetadataDepositPerByte = AssetMetadataDepositPerByte;
+	type ApprovalDeposit = AssetApprovalDeposit;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Holder = ();
+	type CallbackHandle = ();
+	type RemoveItemsLimit = ConstU32<1000>;
+	type WeightInfo = pezpallet_assets::weights::BizinikiwiWeight<Runtime>;
+}
+
+// Implements the types required for the asset transaction payment pezpallet.
+impl pezpallet_asset_tx_payment::Config for Runtime {
+	type Fungibles = Assets;
+	// Fees in an asset are the native fee, converted at the ratio of the asset's minimum balance
+	// to the existential deposit.
+	type OnChargeAssetTransaction = pezpallet_asset_tx_payment::FungiblesAdapter<
+		pezpallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>,
+		fees::AssetFeesToTreasury,
+	>;
+	type WeightInfo = pezpallet_asset_tx_payment::weights::BizinikiwiWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = fees::AssetTxPaymentBenchmarkHelper;
+}
+
+parameter_types! {
+	/// Template entries may be up to 256 bytes long.
+	pub const TemplateMaxEntryLen: u32 = 256;
+	/// A template entry: its key and the length of its bytes.
+	pub const TemplateEntryDepositBase: interface::Balance = deposit(1, 50);
+	/// Each byte of a template entry.
+	pub const TemplateEntryDepositPerByte: interface::Balance = deposit(0, 1);
+	/// Unsigned template updates have a low, fixed priority, so that transactions paying a fee
+	/// usually go first.
+	pub const TemplateUnsignedPriority: pezsp_runtime::transaction_validity::TransactionPriority =
+		1_000;
+	/// Offchain workers submit a template update, signed or unsigned, once every 10 blocks.
+	pub const TemplateUnsignedInterval: BlockNumberFor<Runtime> = 10;
 }
 
 // Implements the types required for the template pezpallet.
-impl pezpallet_minimal_template::Config for Runtime {}
+impl pezpallet_minimal_template::Config for Runtime {
+	#[cfg(not(feature = "governance"))]
+	type AdminOrigin = frame_system::EnsureRoot<interface::AccountId>;
+	#[cfg(feature = "governance")]
+	type AdminOrigin = governance::EnsureRootOrCouncilMajority;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type MaxEntryLen = TemplateMaxEntryLen;
+	type EntryDepositBase = TemplateEntryDepositBase;
+	type EntryDepositPerByte = TemplateEntryDepositPerByte;
+	type AuthorityId = pezpallet_minimal_template::crypto::TemplateAuthId;
+	type UnsignedPriority = TemplateUnsignedPriority;
+	type UnsignedInterval = TemplateUnsignedInterval;
+	type WeightInfo = pezpallet_minimal_template::weights::BizinikiwiWeight<Runtime>;
+}
+
+// Implements the types required for the staging instance of the template pezpallet.
+impl pezpallet_minimal_template::Config<pezframe_support::instances::Instance1> for Runtime {
+	#[cfg(not(feature = "governance"))]
+	type AdminOrigin = frame_system::EnsureRoot<interface::AccountId>;
+	#[cfg(feature = "governance")]
+	type AdminOrigin = governance::EnsureRootOrCouncilMajority;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type MaxEntryLen = TemplateMaxEntryLen;
+	type EntryDepositBase = TemplateEntryDepositBase;
+	type EntryDepositPerByte = TemplateEntryDepositPerByte;
+	type AuthorityId = offchain::staging_template_crypto::StagingTemplateAuthId;
+	type UnsignedPriority = TemplateUnsignedPriority;
+	type UnsignedInterval = TemplateUnsignedInterval;
+	type WeightInfo = pezpallet_minimal_template::weights::BizinikiwiWeight<Runtime>;
+}
+
+parameter_types! {
+	/// Multi-block migrations may use up to 80% of each block.
+	pub MbmServiceWeight: pezframe_support::weights::Weight = pezsp_runtime::Perbill::from_percent(80) *
+		<Runtime as frame_system::Config>::BlockWeights::get().max_block;
+}
+
+// Implements the types required for the migrations pezpallet.
+impl pezpallet_migrations::Config for Runtime {
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Migrations = SteppedMigrations;
+	// Benchmarks need to control which migrations run.
+	#[cfg(feature = "runtime-benchmarks")]
+	type Migrations = pezpallet_migrations::mock_helpers::MockedMigrations;
+	type CursorMaxLen = ConstU32<65_536>;
+	type IdentifierMaxLen = ConstU32<256>;
+	type MigrationStatusHandler = ();
+	type FailedMigrationHandler = pezframe_support::migrations::FreezeChainOnFailedMigration;
+	type MaxServiceWeight = MbmServiceWeight;
+	type WeightInfo = pezpallet_migrations::weights::BizinikiwiWeight<Runtime>;
+}
+
+/// Lets pezpallets build extrinsics in their offchain workers, which the node then submits to
+/// its transaction pool.
+pub mod offchain {
+	use super::*;
+	use codec::Encode;
+	use frame_system::offchain::{
+		AppCrypto, CreateBare, CreateSignedTransaction, CreateTransaction, CreateTransactionBase,
+		SigningTypes,
+	};
+	use pezsp_runtime::{
+		generic::{Era, SignedPayload},
+		KeyTypeId, MultiSignature, MultiSigner, SaturatedConversion,
+	};
+
+	/// The key type of the keys the staging template's offchain worker signs with. It differs from
+	/// [`pezpallet_minimal_template::KEY_TYPE`], so that the two template instances sign from
+	/// different accounts instead of racing for the same nonce.
+	pub const STAGING_TEMPLATE_KEY_TYPE: KeyTypeId = KeyTypeId(*b"tmps");
+
+	/// The sr25519 keys the staging template's offchain worker signs with, kept in the node's
+	/// keystore under [`STAGING_TEMPLATE_KEY_TYPE`].
+	pub mod staging_template_crypto {
+		use super::STAGING_TEMPLATE_KEY_TYPE;
+		use pezkuwi_sdk::{
+			pezframe_system::offchain::AppCrypto,
+			pezsp_runtime::{
+				app_crypto::{app_crypto, sr25519},
+				MultiSignature, MultiSigner,
+			},
+		};
+
+		app_crypto!(sr25519, STAGING_TEMPLATE_KEY_TYPE);
+
+		/// Signs on behalf of the account of a [`Public`] key.
+		pub struct StagingTemplateAuthId;
+
+		impl AppCrypto<MultiSigner, MultiSignature> for StagingTemplateAuthId {
+			type RuntimeAppPublic = Public;
+			type GenericSignature = sr25519::Signature;
+			type GenericPublic = sr25519::Public;
+		}
+	}
+
+	impl SigningTypes for Runtime {
+		type Public = MultiSigner;
+		type Signature = MultiSignature;
+	}
+
+	impl<C> CreateTransactionBase<C> for Runtime
+	where
+		RuntimeCall: From<C>,
+	{
+		type Extrinsic = ExtrinsicFor<Runtime>;
+		type RuntimeCall = RuntimeCall;
+	}
+
+	impl<C> CreateBare<C> for Runtime
+	where
+		RuntimeCall: From<C>,
+	{
+		fn create_bare(call: RuntimeCall) -> ExtrinsicFor<Runtime> {
+			ExtrinsicFor::<Runtime>::new_bare(call)
+		}
+	}
+
+	impl<C> CreateTransaction<C> for Runtime
+	where
+		RuntimeCall: From<C>,
+	{
+		type Extension = TxExtension;
+
+		fn create_transaction(call: RuntimeCall, extension: TxExtension) -> ExtrinsicFor<Runtime> {
+			ExtrinsicFor::<Runtime>::new_transaction(call, extension)
+		}
+	}
+
+	impl<C> CreateSignedTransaction<C> for Runtime
+	where
+		RuntimeCall: From<C>,
+	{
+		/// Sign `call` with `public` as `account`, whose next nonce is `nonce`.
+		///
+		/// The extrinsic pays its fee in the native token, without a tip, and is mortal, valid for
+		/// half of [`frame_system::Config::BlockHashCount`] blocks from the block the offchain
+		/// worker runs on top of.
+		fn create_signed_transaction<A: AppCrypto<MultiSigner, MultiSignature>>(
+			call: RuntimeCall,
+			public: MultiSigner,
+			account: interface::AccountId,
+			nonce: interface::Nonce,
+		) -> Option<ExtrinsicFor<Runtime>> {
+			let period = <Runtime as frame_system::Config>::BlockHashCount::get()
+				.checked_next_power_of_two()
+				.map(|c| c / 2)
+				.unwrap_or(2) as u64;
+			let current_block = System::block_number().saturated_into::<u64>();
+			let tx_ext: TxExtension = (
+				frame_system::CheckNonZeroSender::new(),
+				frame_system::CheckSpecVersion::new(),
+				frame_system::CheckTxVersion::new(),
+				frame_system::CheckGenesis::new(),
+				frame_system::CheckEra::from(Era::mortal(period, current_block)),
+				frame_system::CheckNonce::from(nonce),
+				frame_system::CheckWeight::new(),
+				pezpallet_asset_tx_payment::ChargeAssetTxPayment::from(0, None),
+				frame_system::WeightReclaim::new(),
+			);
+			let payload = SignedPayload::new(call, tx_ext).ok()?;
+			let signature = payload.using_encoded(|payload| A::sign(payload, public))?;
+			let (call, tx_ext, _) = payload.deconstruct();
+
+			Some(ExtrinsicFor::<Runtime>::new_signed(call, account.into(), signature, tx_ext))
+		}
+	}
+}
 
 type Block = frame::runtime::types_common::BlockOf<Runtime, TxExtension>;
 type Header = HeaderFor<Runtime>;
 
-type RuntimeExecutive =
-	Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPalletsWithSystem>;
+// `define_benchmarks!` does not take `cfg` attributes, so there is one list per feature set.
+#[cfg(all(feature = "runtime-benchmarks", not(feature = "governance")))]
+mod benches {
+	pezframe_benchmarking::define_benchmarks!(
+		[pezframe_system, SystemBench::<Runtime>]
+		[pezpallet_balances, Balances]
+		[pezpallet_timestamp, Timestamp]
+		[pezpallet_sudo, Sudo]
+		[pezpallet_minimal_template, Template]
+		[pezpallet_migrations, MultiBlockMigrations]
+		[pezpallet_utility, Utility]
+		[pezpallet_proxy, Proxy]
+		[pezpallet_multisig, Multisig]
+		[pezpallet_assets, Assets]
+		[pezpallet_asset_tx_payment, AssetTxPayment]
+	);
+}
+
+#[cfg(all(feature = "runtime-benchmarks", feature = "governance"))]
+mod benches {
+	pezframe_benchmarking::define_benchmarks!(
+		[pezframe_system, SystemBench::<Runtime>]
+		[pezpallet_balances, Balances]
+		[pezpallet_timestamp, Timestamp]
+		[pezpallet_minimal_template, Template]
+		[pezpallet_migrations, MultiBlockMigrations]
+		[pezpallet_utility, Utility]
+		[pezpallet_proxy, Proxy]
+		[pezpallet_multisig, Multisig]
+		[pezpallet_preimage, Preimage]
+		[pezpallet_scheduler, Scheduler]
+		[pezpallet_collective, Council]
+		[pezpallet_conviction_voting, ConvictionVoting]
+		[pezpallet_referenda, Referenda]
+		[pezpallet_assets, Assets]
+		[pezpallet_asset_tx_payment, AssetTxPayment]
+	);
+}
+
+/// The migrations to run on the next runtime upgrade, in order.
+///
+/// Remove a migration once every chain running this runtime has applied it.
+pub type Migrations = (
+	pezpallet_minimal_template::migrations::v1::MigrateV0ToV1<Runtime>,
+	// Only sets the storage version of `StagingTemplate` on chains that predate it.
+	pezpallet_minimal_template::migrations::v1::MigrateV0ToV1<
+		Runtime,
+		pezframe_support::instances::Instance1,
+	>,
+);
+
+/// The multi-block migrations run by [`MultiBlockMigrations`], in order.
+///
+/// They start on the next runtime upgrade and only run once; transactions are not included in
+/// blocks until all of them are done.
+pub type SteppedMigrations =
+	(pezpallet_minimal_template::migrations::prune::PruneStoreCounts<Runtime>,);
+
+/// The executive used to apply blocks and extrinsics to this runtime.
+pub type RuntimeExecutive = Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPalletsWithSystem,
+	Migrations,
+>;
 
 impl_runtime_apis! {
 	use pezsp_runtime::traits::BlockT;
@@ -249,16 +1155,14 @@ impl_runtime_apis! {
 		}
 
 		fn inherent_extrinsics(data: InherentData) -> Vec<ExtrinsicFor<Runtime>> {
-			// TODO: Implement proper inherent extrinsics creation
-			Vec::new()
+			data.create_extrinsics()
 		}
 
 		fn check_inherents(
 			block: <Block as BlockT>::LazyBlock,
 			data: InherentData,
 		) -> CheckInherentsResult {
-			// TODO: Implement proper inherents checking
-			CheckInherentsResult::new()
+			data.check_extrinsics(&block.into())
 		}
 	}
 
@@ -279,14 +1183,54 @@ impl_runtime_apis! {
 	}
 
 	impl apis::SessionKeys<Block> for Runtime {
-		fn generate_session_keys(_seed: Option<Vec<u8>>) -> Vec<u8> {
-			Default::default()
+		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
+			opaque::SessionKeys::generate(seed)
 		}
 
 		fn decode_session_keys(
-			_encoded: Vec<u8>,
+			encoded: Vec<u8>,
 		) -> Option<Vec<(Vec<u8>, apis::KeyTypeId)>> {
-			Default::default()
+			opaque::SessionKeys::decode_into_raw_public_keys(&encoded)
+		}
+	}
+
+	impl pezsp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
+		fn slot_duration() -> pezsp_consensus_aura::SlotDuration {
+			pezsp_consensus_aura::SlotDuration::from_millis(Aura::slot_duration())
+		}
+
+		fn authorities() -> Vec<AuraId> {
+			pezpallet_aura::Authorities::<Runtime>::get().into_inner()
+		}
+	}
+
+	impl pezsp_consensus_grandpa::GrandpaApi<Block> for Runtime {
+		fn grandpa_authorities() -> pezsp_consensus_grandpa::AuthorityList {
+			Grandpa::grandpa_authorities()
+		}
+
+		fn current_set_id() -> pezsp_consensus_grandpa::SetId {
+			Grandpa::current_set_id()
+		}
+
+		fn submit_report_equivocation_unsigned_extrinsic(
+			_equivocation_proof: pezsp_consensus_grandpa::EquivocationProof<
+				<Block as BlockT>::Hash,
+				NumberFor<Block>,
+			>,
+			_key_owner_proof: pezsp_consensus_grandpa::OpaqueKeyOwnershipProof,
+		) -> Option<()> {
+			None
+		}
+
+		fn generate_key_ownership_proof(
+			_set_id: pezsp_consensus_grandpa::SetId,
+			_authority_id: pezsp_consensus_grandpa::AuthorityId,
+		) -> Option<pezsp_consensus_grandpa::OpaqueKeyOwnershipProof> {
+			// NOTE: this is the only implementation possible since we've
+			// defined our key owner proof type as a bottom type (i.e. a type
+			// with no values).
+			None
 		}
 	}
 
@@ -314,6 +1258,15 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pezframe_support::view_functions::runtime_api::RuntimeViewFunction<Block> for Runtime {
+		fn execute_view_function(
+			id: pezframe_support::view_functions::ViewFunctionId,
+			input: Vec<u8>,
+		) -> Result<Vec<u8>, pezframe_support::view_functions::ViewFunctionDispatchError> {
+			Runtime::execute_view_function(id, input)
+		}
+	}
+
 	impl apis::GenesisBuilder<Block> for Runtime {
 		fn build_state(config: Vec<u8>) -> pezsp_genesis_builder::Result {
 			build_state::<RuntimeGenesisConfig>(config)
@@ -327,6 +1280,74 @@ impl_runtime_apis! {
 			self::genesis_config_presets::preset_names()
 		}
 	}
+
+	#[cfg(feature = "try-runtime")]
+	impl pezframe_try_runtime::TryRuntime<Block> for Runtime {
+		fn on_runtime_upgrade(
+			checks: pezframe_try_runtime::UpgradeCheckSelect,
+		) -> (Weight, Weight) {
+			// NOTE: intentional unwrap: we don't want to propagate the error backwards, and want to
+			// have a backtrace here. If any of the pre/post migration checks fail, we shall stop
+			// right here and right now.
+			let weight = RuntimeExecutive::try_runtime_upgrade(checks).unwrap();
+			(weight, <Runtime as frame_system::Config>::BlockWeights::get().max_block)
+		}
+
+		fn execute_block(
+			block: <Block as BlockT>::LazyBlock,
+			state_root_check: bool,
+			signature_check: bool,
+			select: pezframe_try_runtime::TryStateSelect,
+		) -> Weight {
+			// NOTE: intentional unwrap: we don't want to propagate the error backwards, and want to
+			// have a backtrace here.
+			RuntimeExecutive::try_execute_block(
+				block.into(),
+				state_root_check,
+				signature_check,
+				select,
+			)
+			.expect("execute-block failed")
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl pezframe_benchmarking::Benchmark<Block> for Runtime {
+		fn benchmark_metadata(extra: bool) -> (
+			Vec<pezframe_benchmarking::BenchmarkList>,
+			Vec<pezframe_support::traits::StorageInfo>,
+		) {
+			use pezframe_benchmarking::BenchmarkList;
+			use pezframe_support::traits::StorageInfoTrait;
+			use pezframe_system_benchmarking::Pezpallet as SystemBench;
+
+			let mut list = Vec::<BenchmarkList>::new();
+			list_benchmarks!(list, extra);
+
+			let storage_info = AllPalletsWithSystem::storage_info();
+			(list, storage_info)
+		}
+
+		#[allow(non_local_definitions)]
+		fn dispatch_benchmark(
+			config: pezframe_benchmarking::BenchmarkConfig,
+		) -> Result<Vec<pezframe_benchmarking::BenchmarkBatch>, alloc::string::String> {
+			use pezframe_benchmarking::BenchmarkBatch;
+			use pezframe_support::traits::{TrackedStorageKey, WhitelistedStorageKeys};
+			use pezframe_system_benchmarking::Pezpallet as SystemBench;
+
+			impl pezframe_system_benchmarking::Config for Runtime {}
+
+			let whitelist: Vec<TrackedStorageKey> =
+				AllPalletsWithSystem::whitelisted_storage_keys();
+
+			let mut batches = Vec::<BenchmarkBatch>::new();
+			let params = (&config, &whitelist);
+			add_benchmarks!(params, batches);
+
+			Ok(batches)
+		}
+	}
 }
 
 /// Some re-exports that the node side code needs to know. Some are useful in this context as well.
@@ -336,7 +1357,7 @@ impl_runtime_apis! {
 // https://github.com/paritytech/substrate/issues/10579#issuecomment-1600537558
 pub mod interface {
 	use super::{Runtime, frame_system};
-	use pezkuwi_sdk::{pezkuwi_sdk_frame as frame, *, pezpallet_balances};
+	use pezkuwi_sdk::{pezkuwi_sdk_frame as frame, *, pezpallet_assets, pezpallet_balances};
 
 	pub type Block = super::Block;
 	pub use frame::runtime::types_common::OpaqueBlock;
@@ -345,4 +1366,5 @@ pub mod interface {
 	pub type Hash = <Runtime as frame_system::Config>::Hash;
 	pub type Balance = <Runtime as pezpallet_balances::Config>::Balance;
 	pub type MinimumBalance = <Runtime as pezpallet_balances::Config>::ExistentialDeposit;
+	pub type AssetId = <Runtime as pezpallet_assets::Config>::AssetId;
 }
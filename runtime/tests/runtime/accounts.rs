@@ -0,0 +1,169 @@
+use crate::common::*;
+use pez_minimal_template_runtime::{
+	Balances, Multisig, Proxy, ProxyDepositBase, ProxyDepositFactor, ProxyType, Runtime,
+	RuntimeCall, RuntimeEvent, RuntimeOrigin, System, Template, Utility, UNIT,
+};
+use pezkuwi_sdk::{
+	pezframe_support::{
+		assert_ok,
+		dispatch::GetDispatchInfo,
+		traits::{fungible::Inspect, Get, ReservableCurrency},
+	},
+	pezframe_system, pezpallet_balances, pezpallet_proxy,
+	pezsp_keyring::Sr25519Keyring::{self, Alice, Bob, Charlie, Dave, Ferdie},
+	pezsp_runtime::DispatchError,
+};
+
+fn transfer(to: Sr25519Keyring, value: u128) -> RuntimeCall {
+	RuntimeCall::Balances(pezpallet_balances::Call::transfer_keep_alive {
+		dest: to.to_account_id().into(),
+		value,
+	})
+}
+
+fn balance_of(who: Sr25519Keyring) -> u128 {
+	Balances::balance(&who.to_account_id())
+}
+
+/// Build the development genesis and move to block 1, so events are recorded.
+fn new_ext() -> pezkuwi_sdk::pezsp_io::TestExternalities {
+	let mut ext = new_test_ext();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+#[test]
+fn batch_all_reverts_every_call_if_one_fails() {
+	new_ext().execute_with(|| {
+		let before = balance_of(Ferdie);
+		let calls = vec![transfer(Ferdie, UNIT), transfer(Ferdie, u128::MAX)];
+
+		assert!(Utility::batch_all(RuntimeOrigin::signed(Alice.to_account_id()), calls).is_err());
+		assert_eq!(balance_of(Ferdie), before);
+	});
+}
+
+#[test]
+fn force_batch_keeps_the_calls_that_succeed() {
+	new_ext().execute_with(|| {
+		let before = balance_of(Ferdie);
+		let calls = vec![transfer(Ferdie, UNIT), transfer(Ferdie, u128::MAX)];
+
+		assert_ok!(Utility::force_batch(RuntimeOrigin::signed(Alice.to_account_id()), calls));
+		assert_eq!(balance_of(Ferdie), before + UNIT);
+	});
+}
+
+#[test]
+fn template_proxy_can_only_call_the_template_pezpallet() {
+	new_ext().execute_with(|| {
+		let (alice, bob) = (Alice.to_account_id(), Bob.to_account_id());
+		assert_ok!(Proxy::add_proxy(
+			RuntimeOrigin::signed(alice.clone()),
+			bob.clone().into(),
+			ProxyType::Template,
+			0
+		));
+		assert_eq!(
+			Balances::reserved_balance(&alice),
+			ProxyDepositBase::get() + ProxyDepositFactor::get()
+		);
+
+		let set_value =
+			RuntimeCall::Template(pezpallet_minimal_template::Call::set_value { value: 7 });
+		assert_ok!(Proxy::proxy(
+			RuntimeOrigin::signed(bob.clone()),
+			alice.clone().into(),
+			None,
+			Box::new(set_value)
+		));
+		assert_eq!(Template::get_value_record().and_then(|r| r.owner), Some(alice.clone()));
+
+		let before = balance_of(Ferdie);
+		assert_ok!(Proxy::proxy(
+			RuntimeOrigin::signed(bob),
+			alice.into(),
+			None,
+			Box::new(transfer(Ferdie, UNIT))
+		));
+		System::assert_last_event(RuntimeEvent::Proxy(pezpallet_proxy::Event::ProxyExecuted {
+			result: Err(pezframe_system::Error::<Runtime>::CallFiltered.into()),
+		}));
+		assert_eq!(balance_of(Ferdie), before);
+	});
+}
+
+#[test]
+fn balances_proxy_cannot_sneak_template_calls_into_a_batch() {
+	new_ext().execute_with(|| {
+		let (alice, bob) = (Alice.to_account_id(), Bob.to_account_id());
+		assert_ok!(Proxy::add_proxy(
+			RuntimeOrigin::signed(alice.clone()),
+			bob.clone().into(),
+			ProxyType::Balances,
+			0
+		));
+
+		let before = balance_of(Ferdie);
+		let batch = RuntimeCall::Utility(pezkuwi_sdk::pezpallet_utility::Call::batch_all {
+			calls: vec![
+				transfer(Ferdie, UNIT),
+				RuntimeCall::Template(pezpallet_minimal_template::Call::set_value { value: 7 }),
+			],
+		});
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(bob), alice.into(), None, Box::new(batch)));
+
+		let executed = System::events().into_iter().find_map(|record| match record.event {
+			RuntimeEvent::Proxy(pezpallet_proxy::Event::ProxyExecuted { result }) => Some(result),
+			_ => None,
+		});
+		assert!(matches!(executed, Some(Err(DispatchError::Module(_)))));
+		assert_eq!(balance_of(Ferdie), before);
+		assert_eq!(Template::get_value(), Some(42));
+	});
+}
+
+#[test]
+fn two_of_three_multisig_transfers_after_the_second_approval() {
+	new_ext().execute_with(|| {
+		let mut signatories =
+			vec![Alice.to_account_id(), Bob.to_account_id(), Charlie.to_account_id()];
+		signatories.sort();
+		let others = |who: Sr25519Keyring| {
+			signatories.iter().filter(|s| **s != who.to_account_id()).cloned().collect::<Vec<_>>()
+		};
+		let multisig = Multisig::multi_account_id(&signatories, 2);
+		assert_ok!(Balances::transfer_keep_alive(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			multisig.clone().into(),
+			10 * UNIT
+		));
+
+		let call = transfer(Dave, UNIT);
+		let max_weight = call.get_dispatch_info().call_weight;
+		let dave_before = balance_of(Dave);
+
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			2,
+			others(Alice),
+			None,
+			Box::new(call.clone()),
+			max_weight
+		));
+		assert_eq!(balance_of(Dave), dave_before);
+		assert!(Balances::reserved_balance(&Alice.to_account_id()) > 0);
+
+		let timepoint = Multisig::timepoint();
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(Charlie.to_account_id()),
+			2,
+			others(Charlie),
+			Some(timepoint),
+			Box::new(call),
+			max_weight
+		));
+		assert_eq!(balance_of(Dave), dave_before + UNIT);
+		assert_eq!(Balances::reserved_balance(&Alice.to_account_id()), 0);
+	});
+}
@@ -0,0 +1,93 @@
+use crate::common::*;
+use codec::Encode;
+use pez_minimal_template_runtime::{
+	fees::TreasuryAccount,
+	genesis_config_presets::{TEST_ASSET_ID, TEST_ASSET_UNITS_PER_NATIVE_UNIT},
+	interface::{AccountId, AssetId, Balance},
+	Assets, Balances, Runtime, RuntimeCall, RuntimeExecutive, System, TransactionPayment,
+	SLOT_DURATION,
+};
+use pezkuwi_sdk::{
+	pezframe_support::{
+		dispatch::GetDispatchInfo,
+		traits::{fungible::Inspect, fungibles, Get},
+	},
+	pezframe_system,
+	pezkuwi_sdk_frame::runtime::prelude::ExtrinsicFor,
+	pezsp_keyring::Sr25519Keyring::Alice,
+};
+
+/// A `System::remark` signed by Alice, paying its fee in `asset_id`, or in the native token if it
+/// is `None`.
+fn signed_remark(asset_id: Option<AssetId>) -> ExtrinsicFor<Runtime> {
+	let call = RuntimeCall::System(pezframe_system::Call::remark { remark: vec![0; 32] });
+	signed_by(Alice, call, asset_id)
+}
+
+/// The fee of `xt` in the native token.
+fn native_fee(xt: &ExtrinsicFor<Runtime>) -> Balance {
+	TransactionPayment::compute_fee(xt.encoded_size() as u32, &xt.get_dispatch_info(), 0)
+}
+
+fn asset_balance(who: &AccountId) -> Balance {
+	Assets::balance(TEST_ASSET_ID, who)
+}
+
+#[test]
+fn presets_create_the_test_asset_as_sufficient() {
+	new_test_ext().execute_with(|| {
+		let alice = Alice.to_account_id();
+
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::minimum_balance(TEST_ASSET_ID),
+			TEST_ASSET_UNITS_PER_NATIVE_UNIT * Balances::minimum_balance()
+		);
+		assert!(asset_balance(&alice) > 0);
+		assert_eq!(System::account(&alice).sufficients, 1);
+	});
+}
+
+#[test]
+fn fees_are_paid_in_the_native_token_by_default() {
+	new_test_ext().execute_with(|| {
+		let alice = Alice.to_account_id();
+		let (native_before, asset_before) = (Balances::balance(&alice), asset_balance(&alice));
+
+		initialize_next_block(SLOT_DURATION);
+		let xt = signed_remark(None);
+		let fee = native_fee(&xt);
+		RuntimeExecutive::apply_extrinsic(xt).unwrap().unwrap();
+
+		assert!(fee > 0);
+		assert_eq!(Balances::balance(&alice), native_before - fee);
+		assert_eq!(asset_balance(&alice), asset_before);
+	});
+}
+
+#[test]
+fn fees_paid_in_an_asset_are_converted_and_go_to_the_treasury() {
+	new_test_ext().execute_with(|| {
+		let alice = Alice.to_account_id();
+		let (native_before, asset_before) = (Balances::balance(&alice), asset_balance(&alice));
+
+		initialize_next_block(SLOT_DURATION);
+		let xt = signed_remark(Some(TEST_ASSET_ID));
+		let fee = native_fee(&xt) * TEST_ASSET_UNITS_PER_NATIVE_UNIT;
+		RuntimeExecutive::apply_extrinsic(xt).unwrap().unwrap();
+
+		assert!(fee > 0);
+		assert_eq!(Balances::balance(&alice), native_before);
+		assert_eq!(asset_balance(&alice), asset_before - fee);
+		assert_eq!(asset_balance(&TreasuryAccount::get()), fee);
+	});
+}
+
+#[test]
+fn fees_cannot_be_paid_in_an_unknown_asset() {
+	new_test_ext().execute_with(|| {
+		initialize_next_block(SLOT_DURATION);
+		let xt = signed_remark(Some(TEST_ASSET_ID + 1));
+
+		assert!(RuntimeExecutive::apply_extrinsic(xt).is_err());
+	});
+}
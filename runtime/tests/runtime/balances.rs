@@ -0,0 +1,88 @@
+use crate::common::*;
+use pez_minimal_template_runtime::{
+	fees::TreasuryAccount, interface::AccountId, Balances, ExistentialDeposit, RuntimeOrigin,
+	System,
+};
+use pezkuwi_sdk::{
+	pezframe_support::{
+		assert_noop, assert_ok,
+		traits::{fungible::Inspect, Get},
+	},
+	pezsp_keyring::Sr25519Keyring::{Alice, Bob, Ferdie},
+	pezsp_runtime::TokenError,
+};
+
+#[test]
+fn transfers_that_leave_less_than_the_existential_deposit_reap_the_sender() {
+	new_test_ext().execute_with(|| {
+		let (ferdie, bob) = (Ferdie.to_account_id(), Bob.to_account_id());
+		let dust = ExistentialDeposit::get() / 2;
+		let amount = Balances::balance(&ferdie) - dust;
+
+		assert_ok!(Balances::transfer_allow_death(
+			RuntimeOrigin::signed(ferdie.clone()),
+			bob.into(),
+			amount
+		));
+
+		assert!(!System::account_exists(&ferdie));
+		assert_eq!(Balances::balance(&ferdie), 0);
+	});
+}
+
+#[test]
+fn dust_of_reaped_accounts_goes_to_the_treasury() {
+	new_test_ext().execute_with(|| {
+		let (ferdie, bob) = (Ferdie.to_account_id(), Bob.to_account_id());
+		let treasury = TreasuryAccount::get();
+		assert_eq!(Balances::balance(&treasury), ExistentialDeposit::get());
+		let dust = ExistentialDeposit::get() / 2;
+		let amount = Balances::balance(&ferdie) - dust;
+
+		assert_ok!(Balances::transfer_allow_death(
+			RuntimeOrigin::signed(ferdie),
+			bob.into(),
+			amount
+		));
+
+		assert_eq!(Balances::balance(&treasury), ExistentialDeposit::get() + dust);
+	});
+}
+
+#[test]
+fn transfer_keep_alive_cannot_reap_the_sender() {
+	new_test_ext().execute_with(|| {
+		let (ferdie, bob) = (Ferdie.to_account_id(), Bob.to_account_id());
+		let amount = Balances::balance(&ferdie) - ExistentialDeposit::get() / 2;
+
+		assert!(Balances::transfer_keep_alive(
+			RuntimeOrigin::signed(ferdie.clone()),
+			bob.into(),
+			amount
+		)
+		.is_err());
+		assert!(System::account_exists(&ferdie));
+	});
+}
+
+#[test]
+fn new_accounts_need_the_existential_deposit() {
+	new_test_ext().execute_with(|| {
+		let alice = Alice.to_account_id();
+		let (below, at) = (AccountId::from([1; 32]), AccountId::from([2; 32]));
+
+		assert_noop!(
+			Balances::transfer_allow_death(
+				RuntimeOrigin::signed(alice.clone()),
+				below.into(),
+				ExistentialDeposit::get() - 1
+			),
+			TokenError::BelowMinimum
+		);
+		assert_ok!(Balances::transfer_allow_death(
+			RuntimeOrigin::signed(alice),
+			at.into(),
+			ExistentialDeposit::get()
+		));
+	});
+}
@@ -0,0 +1,76 @@
+use crate::common::*;
+use pez_minimal_template_runtime::{Runtime, RuntimeCall, RuntimeExecutive, System, SLOT_DURATION};
+use pezkuwi_sdk::{
+	pezframe_support::{
+		dispatch::DispatchClass,
+		traits::Get,
+		weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
+	},
+	pezframe_system,
+	pezsp_keyring::Sr25519Keyring::Alice,
+	pezsp_runtime::{transaction_validity::InvalidTransaction, Perbill},
+};
+
+type BlockWeights = <Runtime as pezframe_system::Config>::BlockWeights;
+type BlockLength = <Runtime as pezframe_system::Config>::BlockLength;
+
+/// The weight normal extrinsics have used in the current block.
+fn normal_weight() -> Weight {
+	*pezframe_system::BlockWeight::<Runtime>::get().get(DispatchClass::Normal)
+}
+
+fn remark(len: usize) -> RuntimeCall {
+	RuntimeCall::System(pezframe_system::Call::remark { remark: vec![0; len] })
+}
+
+#[test]
+fn normal_extrinsics_get_three_quarters_of_each_block() {
+	let weights = BlockWeights::get();
+	let normal = weights.get(DispatchClass::Normal);
+	let operational = weights.get(DispatchClass::Operational);
+
+	assert_eq!(weights.max_block.ref_time(), WEIGHT_REF_TIME_PER_SECOND);
+	assert_eq!(normal.max_total, Some(Perbill::from_percent(75) * weights.max_block));
+	assert_eq!(operational.max_total, Some(weights.max_block));
+
+	let length = BlockLength::get();
+	assert_eq!(*length.max.get(DispatchClass::Normal), Perbill::from_percent(75) * (5 << 20));
+	assert_eq!(*length.max.get(DispatchClass::Operational), 5 << 20);
+}
+
+#[test]
+fn check_weight_rejects_extrinsics_that_overflow_a_full_block() {
+	new_test_ext().execute_with(|| {
+		initialize_next_block(SLOT_DURATION);
+		assert!(RuntimeExecutive::apply_extrinsic(signed_by(Alice, remark(32), None))
+			.unwrap()
+			.is_ok());
+
+		// Fill what is left of the block for normal extrinsics.
+		let max_normal = BlockWeights::get().get(DispatchClass::Normal).max_total.unwrap();
+		let consumed = normal_weight();
+		System::register_extra_weight_unchecked(
+			max_normal.saturating_sub(consumed),
+			DispatchClass::Normal,
+		);
+
+		assert_eq!(
+			RuntimeExecutive::apply_extrinsic(signed_by(Alice, remark(32), None)),
+			Err(InvalidTransaction::ExhaustsResources.into())
+		);
+		assert_eq!(normal_weight(), max_normal);
+	});
+}
+
+#[test]
+fn check_weight_rejects_extrinsics_longer_than_the_normal_block_length() {
+	new_test_ext().execute_with(|| {
+		initialize_next_block(SLOT_DURATION);
+		let max_normal = *BlockLength::get().max.get(DispatchClass::Normal) as usize;
+
+		assert_eq!(
+			RuntimeExecutive::apply_extrinsic(signed_by(Alice, remark(max_normal), None)),
+			Err(InvalidTransaction::ExhaustsResources.into())
+		);
+	});
+}
@@ -0,0 +1,107 @@
+//! Helpers shared by the runtime tests.
+
+use codec::Encode;
+use pez_minimal_template_runtime::{
+	genesis_config_presets,
+	interface::{AssetId, Block},
+	Runtime, RuntimeCall, RuntimeExecutive, RuntimeGenesisConfig, System, Timestamp, TxExtension,
+	SLOT_DURATION,
+};
+use pezkuwi_sdk::{
+	pezframe_support::{
+		genesis_builder_helper::build_state,
+		inherent::{InherentData, ProvideInherent},
+	},
+	pezframe_system,
+	pezkuwi_sdk_frame::runtime::prelude::ExtrinsicFor,
+	pezpallet_asset_tx_payment::ChargeAssetTxPayment,
+	pezsp_consensus_aura::{Slot, AURA_ENGINE_ID},
+	pezsp_genesis_builder::{PresetId, DEV_RUNTIME_PRESET},
+	pezsp_io::TestExternalities,
+	pezsp_keyring::Sr25519Keyring,
+	pezsp_runtime::{
+		generic::{Era, SignedPayload},
+		traits::{Block as BlockT, Header as HeaderT},
+		Digest, DigestItem, ExtrinsicInclusionMode,
+	},
+	pezsp_timestamp::INHERENT_IDENTIFIER,
+};
+
+pub type Header = <Block as BlockT>::Header;
+
+/// Build test externalities from the development genesis preset.
+pub fn new_test_ext() -> TestExternalities {
+	new_test_ext_from_preset(DEV_RUNTIME_PRESET)
+}
+
+/// Build test externalities from the genesis preset `id`.
+pub fn new_test_ext_from_preset(id: &str) -> TestExternalities {
+	let preset = genesis_config_presets::get_preset(&PresetId::from(id))
+		.unwrap_or_else(|| panic!("the {id} preset exists"));
+
+	let mut ext = TestExternalities::default();
+	ext.execute_with(|| {
+		build_state::<RuntimeGenesisConfig>(preset)
+			.unwrap_or_else(|e| panic!("the {id} preset builds: {e}"))
+	});
+	ext
+}
+
+/// Initialize the block that follows the current one, authored in the Aura slot of `now`.
+///
+/// Returns which extrinsics the block may include.
+pub fn initialize_next_block(now: u64) -> ExtrinsicInclusionMode {
+	let number = pezframe_system::Pezpallet::<Runtime>::block_number() + 1;
+	let parent_hash = pezframe_system::Pezpallet::<Runtime>::parent_hash();
+	let slot = Slot::from(now / SLOT_DURATION);
+	let digest = Digest { logs: vec![DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode())] };
+	let header = Header::new(number, Default::default(), Default::default(), parent_hash, digest);
+
+	RuntimeExecutive::initialize_block(&header)
+}
+
+/// Inherent data holding the timestamp `now`.
+pub fn timestamp_inherent_data(now: u64) -> InherentData {
+	let mut data = InherentData::new();
+	data.put_data(INHERENT_IDENTIFIER, &now).unwrap();
+	data
+}
+
+/// Build, apply and finalize a block whose only extrinsic is the timestamp inherent.
+///
+/// Returns which extrinsics the block could have included.
+pub fn build_block(now: u64) -> ExtrinsicInclusionMode {
+	let mode = initialize_next_block(now);
+
+	let call = Timestamp::create_inherent(&timestamp_inherent_data(now))
+		.expect("the timestamp pezpallet always creates an inherent; qed");
+	let xt = ExtrinsicFor::<Runtime>::new_bare(call.into());
+	RuntimeExecutive::apply_extrinsic(xt).unwrap().unwrap();
+
+	RuntimeExecutive::finalize_block();
+	mode
+}
+
+/// An immortal extrinsic calling `call`, signed by `who` with their current nonce and no tip,
+/// paying its fee in `asset_id`, or in the native token if it is `None`.
+pub fn signed_by(
+	who: Sr25519Keyring,
+	call: RuntimeCall,
+	asset_id: Option<AssetId>,
+) -> ExtrinsicFor<Runtime> {
+	let tx_ext: TxExtension = (
+		pezframe_system::CheckNonZeroSender::new(),
+		pezframe_system::CheckSpecVersion::new(),
+		pezframe_system::CheckTxVersion::new(),
+		pezframe_system::CheckGenesis::new(),
+		pezframe_system::CheckEra::from(Era::Immortal),
+		pezframe_system::CheckNonce::from(System::account_nonce(who.to_account_id())),
+		pezframe_system::CheckWeight::new(),
+		ChargeAssetTxPayment::from(0, asset_id),
+		pezframe_system::WeightReclaim::new(),
+	);
+	let payload = SignedPayload::new(call.clone(), tx_ext.clone()).unwrap();
+	let signature = payload.using_encoded(|payload| who.sign(payload));
+
+	ExtrinsicFor::<Runtime>::new_signed(call, who.to_account_id().into(), signature.into(), tx_ext)
+}
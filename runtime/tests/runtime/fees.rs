@@ -0,0 +1,76 @@
+use crate::common::*;
+use pez_minimal_template_runtime::{
+	fees::{DealWithFees, TreasuryAccount},
+	interface::Balance,
+	Balances, Runtime, TransactionPayment, MILLI_UNIT, UNIT,
+};
+use pezkuwi_sdk::{
+	pezframe_support::{
+		dispatch::{DispatchClass, DispatchInfo},
+		traits::{
+			fungible::{Balanced, Inspect},
+			Get, OnUnbalanced,
+		},
+		weights::{constants::ExtrinsicBaseWeight, Weight},
+	},
+	pezframe_system,
+	pezpallet_transaction_payment::{self, Multiplier},
+	pezsp_runtime::traits::Convert,
+};
+
+/// The fee for a normal extrinsic of `len` bytes with a call weight of `ref_time` and no tip.
+fn fee_for(ref_time: u64, len: u32) -> Balance {
+	let info = DispatchInfo { call_weight: Weight::from_parts(ref_time, 0), ..Default::default() };
+	TransactionPayment::compute_fee(len, &info, 0)
+}
+
+#[test]
+fn weight_fee_of_a_base_extrinsic_is_a_tenth_of_a_milli_unit() {
+	new_test_ext().execute_with(|| {
+		let fee = TransactionPayment::weight_to_fee(ExtrinsicBaseWeight::get());
+		assert!(fee.abs_diff(MILLI_UNIT / 10) <= 1);
+	});
+}
+
+#[test]
+fn fees_scale_with_weight_and_length() {
+	new_test_ext().execute_with(|| {
+		let base = ExtrinsicBaseWeight::get().ref_time();
+
+		assert!(fee_for(2 * base, 100) > fee_for(base, 100));
+		assert!(fee_for(10 * base, 100) > fee_for(2 * base, 100));
+		assert!(fee_for(base, 1_000) > fee_for(base, 100));
+	});
+}
+
+#[test]
+fn fee_multiplier_rises_above_target_fullness_and_falls_below() {
+	new_test_ext().execute_with(|| {
+		type Update = <Runtime as pezpallet_transaction_payment::Config>::FeeMultiplierUpdate;
+		let max_normal = <Runtime as pezframe_system::Config>::BlockWeights::get()
+			.get(DispatchClass::Normal)
+			.max_total
+			.unwrap();
+
+		pezframe_system::Pezpallet::<Runtime>::set_block_consumed_resources(max_normal, 0);
+		assert!(Update::convert(Multiplier::one()) > Multiplier::one());
+
+		pezframe_system::Pezpallet::<Runtime>::set_block_consumed_resources(Weight::zero(), 0);
+		assert!(Update::convert(Multiplier::one()) < Multiplier::one());
+	});
+}
+
+#[test]
+fn fees_are_split_between_treasury_and_burn_and_tips_go_to_treasury() {
+	new_test_ext().execute_with(|| {
+		let issuance = Balances::total_issuance();
+		let treasury = Balances::balance(&TreasuryAccount::get());
+
+		let fees = Balances::issue(10 * UNIT);
+		let tips = Balances::issue(UNIT);
+		DealWithFees::on_unbalanceds([fees, tips].into_iter());
+
+		assert_eq!(Balances::balance(&TreasuryAccount::get()), treasury + 8 * UNIT + UNIT);
+		assert_eq!(Balances::total_issuance(), issuance + 9 * UNIT);
+	});
+}
@@ -0,0 +1,89 @@
+use crate::common::*;
+use pez_minimal_template_runtime::{
+	governance::{CouncilCollective, TracksInfo, DAYS, HOURS, ROOT_TRACK},
+	interface::AccountId,
+	ConvictionVoting, OriginCaller, Preimage, Referenda, Runtime, RuntimeCall, RuntimeOrigin,
+	Scheduler, System, Template, UNIT,
+};
+use pezkuwi_sdk::{
+	pezframe_support::{
+		assert_noop, assert_ok,
+		traits::{schedule::DispatchTime, OnInitialize, StorePreimage},
+	},
+	pezframe_system::RawOrigin,
+	pezpallet_collective,
+	pezpallet_conviction_voting::{AccountVote, Conviction, Vote},
+	pezpallet_referenda::{ReferendumInfo, ReferendumInfoFor, TracksInfo as _},
+	pezsp_keyring::Sr25519Keyring,
+	pezsp_runtime::DispatchError,
+};
+use pezpallet_minimal_template::Value;
+
+fn council(ayes: u32, members: u32) -> RuntimeOrigin {
+	pezpallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(ayes, members).into()
+}
+
+#[test]
+fn council_majority_is_the_template_admin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Template::force_set_value(council(1, 3), 42), DispatchError::BadOrigin);
+
+		assert_ok!(Template::force_set_value(council(2, 3), 42));
+		assert_eq!(Value::<Runtime>::get().map(|r| r.value), Some(42));
+
+		assert_ok!(Template::force_set_value(RuntimeOrigin::root(), 7));
+		assert_eq!(Value::<Runtime>::get().map(|r| r.value), Some(7));
+	});
+}
+
+#[test]
+fn only_root_referenda_have_a_track() {
+	let root: OriginCaller = RawOrigin::Root.into();
+	let signed: OriginCaller = RawOrigin::Signed(AccountId::from([1; 32])).into();
+
+	assert_eq!(TracksInfo::track_for(&root), Ok(ROOT_TRACK));
+	assert_eq!(TracksInfo::track_for(&signed), Err(()));
+}
+
+#[test]
+fn approved_root_referendum_is_enacted() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let alice = RuntimeOrigin::signed(Sr25519Keyring::Alice.to_account_id());
+
+		let call =
+			RuntimeCall::Template(pezpallet_minimal_template::Call::force_set_value { value: 7 });
+		assert_ok!(Referenda::submit(
+			alice.clone(),
+			Box::new(RawOrigin::Root.into()),
+			Preimage::bound(call).unwrap(),
+			DispatchTime::After(0),
+		));
+		assert_ok!(Referenda::place_decision_deposit(alice, 0));
+
+		// Every endowed account votes aye with most of its balance.
+		for voter in Sr25519Keyring::iter() {
+			let vote = Vote { aye: true, conviction: Conviction::Locked1x };
+			assert_ok!(ConvictionVoting::vote(
+				RuntimeOrigin::signed(voter.to_account_id()),
+				0,
+				AccountVote::Standard { vote, balance: 900_000 * UNIT },
+			));
+		}
+
+		// The referendum is prepared, decided once it has been confirmed for a day, and enacted
+		// a day later.
+		let enacted_by = 1 + 2 * HOURS + DAYS + DAYS;
+		let mut now = 1;
+		while Value::<Runtime>::get().map(|r| r.value) == Some(42) {
+			assert!(now <= enacted_by + 10, "the referendum was not enacted by block {enacted_by}");
+			now += 1;
+			System::set_block_number(now);
+			Scheduler::on_initialize(now);
+		}
+
+		assert!(now >= enacted_by, "the referendum was enacted early, at block {now}");
+		assert!(matches!(ReferendumInfoFor::<Runtime>::get(0), Some(ReferendumInfo::Approved(..))));
+		assert_eq!(Value::<Runtime>::get().map(|r| r.value), Some(7));
+	});
+}
@@ -0,0 +1,99 @@
+use crate::common::*;
+use codec::{Decode, Encode};
+use pez_minimal_template_runtime::{
+	interface::Block, Runtime, RuntimeCall, RuntimeExecutive, Timestamp,
+};
+use pezkuwi_sdk::{
+	pezframe_support::inherent::{CheckInherentsResult, ProvideInherent},
+	pezkuwi_sdk_frame::runtime::{apis::BlockBuilder, prelude::ExtrinsicFor},
+	pezpallet_timestamp,
+	pezsp_runtime::traits::{Block as BlockT, Header as HeaderT},
+};
+
+#[test]
+fn timestamp_advances_with_each_block() {
+	new_test_ext().execute_with(|| {
+		build_block(6_000);
+		assert_eq!(Timestamp::now(), 6_000);
+
+		build_block(12_000);
+		assert_eq!(Timestamp::now(), 12_000);
+	});
+}
+
+#[test]
+fn check_inherent_rejects_timestamp_too_far_in_future() {
+	new_test_ext().execute_with(|| {
+		let call = Timestamp::create_inherent(&timestamp_inherent_data(600_000)).unwrap();
+
+		assert!(Timestamp::check_inherent(&call, &timestamp_inherent_data(6_000)).is_err());
+		assert!(Timestamp::check_inherent(&call, &timestamp_inherent_data(600_000)).is_ok());
+	});
+}
+
+#[test]
+#[should_panic(expected = "Timestamp must be updated once in the block")]
+fn block_without_timestamp_is_rejected() {
+	new_test_ext().execute_with(|| {
+		initialize_next_block(6_000);
+		RuntimeExecutive::finalize_block();
+	});
+}
+
+/// Check a block whose only extrinsic is the timestamp inherent for `block_now` through the
+/// `BlockBuilder` runtime API, as an importing node whose clock says `now` would.
+fn check_block_with_timestamp(block_now: u64, now: u64) -> CheckInherentsResult {
+	let call = Timestamp::create_inherent(&timestamp_inherent_data(block_now)).unwrap();
+	let xt = ExtrinsicFor::<Runtime>::new_bare(call.into());
+	let header = Header::new(
+		1,
+		Default::default(),
+		Default::default(),
+		Default::default(),
+		Default::default(),
+	);
+	let block = Block::new(header, vec![xt]);
+	let block = <Block as BlockT>::LazyBlock::decode(&mut &block.encode()[..]).unwrap();
+
+	<Runtime as BlockBuilder<Block>>::check_inherents(block, timestamp_inherent_data(now))
+}
+
+#[test]
+fn inherent_extrinsics_creates_the_timestamp_inherent() {
+	new_test_ext().execute_with(|| {
+		initialize_next_block(6_000);
+
+		let inherents =
+			<Runtime as BlockBuilder<Block>>::inherent_extrinsics(timestamp_inherent_data(6_000));
+
+		assert_eq!(inherents.len(), 1);
+		assert_eq!(
+			inherents[0].function,
+			RuntimeCall::Timestamp(pezpallet_timestamp::Call::set { now: 6_000 })
+		);
+		for xt in inherents {
+			RuntimeExecutive::apply_extrinsic(xt).unwrap().unwrap();
+		}
+		RuntimeExecutive::finalize_block();
+		assert_eq!(Timestamp::now(), 6_000);
+	});
+}
+
+#[test]
+fn check_inherents_accepts_timestamp_within_drift() {
+	new_test_ext().execute_with(|| {
+		assert!(check_block_with_timestamp(6_000, 6_000).ok());
+		// Blocks may be a little ahead of the importing node's clock.
+		assert!(check_block_with_timestamp(12_000, 6_000).ok());
+	});
+}
+
+#[test]
+fn check_inherents_rejects_timestamp_beyond_drift() {
+	new_test_ext().execute_with(|| {
+		let result = check_block_with_timestamp(600_000, 6_000);
+
+		assert!(!result.ok());
+		assert!(result.fatal_error());
+	});
+}
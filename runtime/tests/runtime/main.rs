@@ -0,0 +1,17 @@
+//! Tests for the runtime, run against its genesis presets.
+
+mod accounts;
+mod asset_fees;
+mod balances;
+mod block_limits;
+mod common;
+mod fees;
+#[cfg(feature = "governance")]
+mod governance;
+mod inherents;
+mod migrations;
+mod offchain;
+mod presets;
+mod session;
+mod stepped_migrations;
+mod template_instances;
@@ -0,0 +1,70 @@
+//! Runs the runtime upgrade against a state snapshot.
+//!
+//! By default the snapshot is the development preset. To test against a live chain, export its
+//! state with `pez-minimal-template-node export-state --chain <chain> > snapshot.json` and point
+//! `RUNTIME_SNAPSHOT` at the file. Run with `--features try-runtime` to also execute the
+//! `pre_upgrade`, `post_upgrade` and `try_state` checks of every pezpallet.
+
+use crate::common::*;
+use pez_minimal_template_runtime::{RuntimeExecutive, StagingTemplate, Template};
+use pezkuwi_sdk::{
+	pezframe_support::traits::GetStorageVersion, pezsp_core::bytes::from_hex,
+	pezsp_io::TestExternalities,
+};
+
+/// The environment variable holding the path of an exported state snapshot.
+const SNAPSHOT_ENV: &str = "RUNTIME_SNAPSHOT";
+
+/// Build test externalities from the raw chain spec written by `export-state`.
+fn ext_from_exported_state(path: &str) -> TestExternalities {
+	let spec: serde_json::Value =
+		serde_json::from_slice(&std::fs::read(path).expect("snapshot file is readable"))
+			.expect("snapshot is a JSON chain spec");
+	let top = spec["genesis"]["raw"]["top"]
+		.as_object()
+		.expect("snapshot is a raw chain spec as written by `export-state`");
+
+	let mut ext = TestExternalities::default();
+	for (key, value) in top {
+		let value = value.as_str().expect("storage values are hex strings");
+		ext.insert(from_hex(key).unwrap(), from_hex(value).unwrap());
+	}
+	ext
+}
+
+fn snapshot_ext() -> TestExternalities {
+	match std::env::var(SNAPSHOT_ENV) {
+		Ok(path) => ext_from_exported_state(&path),
+		Err(_) => new_test_ext(),
+	}
+}
+
+#[test]
+fn runtime_upgrade_applies_to_snapshot() {
+	snapshot_ext().execute_with(|| {
+		#[cfg(feature = "try-runtime")]
+		RuntimeExecutive::try_runtime_upgrade(
+			pezkuwi_sdk::pezframe_support::traits::UpgradeCheckSelect::All,
+		)
+		.unwrap();
+		#[cfg(not(feature = "try-runtime"))]
+		RuntimeExecutive::execute_on_runtime_upgrade();
+
+		assert_eq!(Template::on_chain_storage_version(), Template::in_code_storage_version());
+		assert_eq!(
+			StagingTemplate::on_chain_storage_version(),
+			StagingTemplate::in_code_storage_version()
+		);
+	});
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn try_state_holds_for_snapshot() {
+	use pez_minimal_template_runtime::{AllPalletsWithSystem, System};
+	use pezkuwi_sdk::pezframe_support::traits::{TryState, TryStateSelect};
+
+	snapshot_ext().execute_with(|| {
+		AllPalletsWithSystem::try_state(System::block_number(), TryStateSelect::All).unwrap();
+	});
+}
@@ -0,0 +1,163 @@
+use crate::common::*;
+use codec::{Compact, Decode, Encode};
+use pez_minimal_template_runtime::{
+	interface::{AssetId, Balance},
+	offchain::STAGING_TEMPLATE_KEY_TYPE,
+	Runtime, RuntimeCall, RuntimeExecutive, Timestamp, SLOT_DURATION,
+};
+use pezkuwi_sdk::{
+	pezframe_support::{inherent::ProvideInherent, traits::Get},
+	pezframe_system,
+	pezkuwi_sdk_frame::runtime::prelude::ExtrinsicFor,
+	pezsp_consensus_aura::{Slot, AURA_ENGINE_ID},
+	pezsp_core::offchain::{
+		testing::{TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	},
+	pezsp_io::storage,
+	pezsp_keyring::Sr25519Keyring::{Alice, Bob},
+	pezsp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt},
+	pezsp_runtime::{
+		generic::{Era, Preamble},
+		traits::Header as HeaderT,
+		transaction_validity::TransactionSource,
+		Digest, DigestItem,
+	},
+};
+use pezpallet_minimal_template::{Call, KEY_TYPE};
+
+/// When the block the offchain workers run on top of is authored.
+const NOW: u64 = 1_000_000;
+
+/// Apply the timestamp inherent for `now` to the block being built.
+fn apply_timestamp(now: u64) {
+	let call = Timestamp::create_inherent(&timestamp_inherent_data(now))
+		.expect("the timestamp pezpallet always creates an inherent; qed");
+	RuntimeExecutive::apply_extrinsic(ExtrinsicFor::<Runtime>::new_bare(call.into()))
+		.unwrap()
+		.unwrap();
+}
+
+/// Build a block on the development preset and run the offchain workers on top of it. If
+/// `keyed`, the keystore holds Alice's key for `Template` and Bob's key for `StagingTemplate`.
+///
+/// Returns the submitted extrinsics, once each has passed `validate_transaction` on top of the
+/// block, as the pool checks them, and they have all been applied, one after the other, in the
+/// next block.
+fn submitted_by_offchain_workers(keyed: bool) -> Vec<ExtrinsicFor<Runtime>> {
+	let (offchain, _) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let keystore = MemoryKeystore::new();
+	if keyed {
+		keystore.sr25519_generate_new(KEY_TYPE, Some(&Alice.to_seed())).unwrap();
+		keystore.sr25519_generate_new(STAGING_TEMPLATE_KEY_TYPE, Some(&Bob.to_seed())).unwrap();
+	}
+
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(KeystoreExt::new(keystore));
+
+	ext.execute_with(|| {
+		initialize_next_block(NOW);
+		apply_timestamp(NOW);
+		let header = RuntimeExecutive::finalize_block();
+
+		// Like the node, throw away the changes the offchain workers make to the state.
+		storage::start_transaction();
+		RuntimeExecutive::offchain_worker(&header);
+		storage::rollback_transaction();
+
+		let submitted = pool_state
+			.read()
+			.transactions
+			.iter()
+			.map(|tx| ExtrinsicFor::<Runtime>::decode(&mut &tx[..]).unwrap())
+			.collect::<Vec<_>>();
+
+		for xt in &submitted {
+			// Like the node, throw away the changes validation makes to the state.
+			storage::start_transaction();
+			let validity = RuntimeExecutive::validate_transaction(
+				TransactionSource::Local,
+				xt.clone(),
+				header.hash(),
+			);
+			storage::rollback_transaction();
+			assert!(validity.is_ok(), "{xt:?} is invalid: {validity:?}");
+		}
+
+		let now = NOW + SLOT_DURATION;
+		let slot = Slot::from(now / SLOT_DURATION);
+		let digest = Digest { logs: vec![DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode())] };
+		RuntimeExecutive::initialize_block(&Header::new(
+			header.number() + 1,
+			Default::default(),
+			Default::default(),
+			header.hash(),
+			digest,
+		));
+		apply_timestamp(now);
+		for xt in &submitted {
+			assert_eq!(RuntimeExecutive::apply_extrinsic(xt.clone()), Ok(Ok(())), "{xt:?}");
+		}
+		submitted
+	})
+}
+
+#[test]
+fn offchain_signed_extrinsics_are_valid() {
+	let submitted = submitted_by_offchain_workers(true);
+
+	// Each template instance submits its next value, signed by the account of its own key.
+	assert_eq!(submitted.len(), 2);
+	let signer_of = |call: RuntimeCall| {
+		let xt = submitted.iter().find(|xt| xt.function == call).expect("the call was submitted");
+		match &xt.preamble {
+			Preamble::Signed(address, ..) => address.clone(),
+			preamble => panic!("{preamble:?} is not signed"),
+		}
+	};
+	assert_eq!(
+		signer_of(RuntimeCall::Template(Call::set_value { value: 43 })),
+		Alice.to_account_id().into()
+	);
+	assert_eq!(
+		signer_of(RuntimeCall::StagingTemplate(Call::set_value { value: 0 })),
+		Bob.to_account_id().into()
+	);
+}
+
+#[test]
+fn offchain_signed_extrinsics_are_mortal_with_the_next_nonce_and_no_tip() {
+	let submitted = submitted_by_offchain_workers(true);
+
+	// The workers sign on top of block 1, for half of the block hashes the runtime keeps.
+	let period =
+		<Runtime as pezframe_system::Config>::BlockHashCount::get().next_power_of_two() as u64 / 2;
+	for xt in submitted {
+		let Preamble::Signed(_, _, tx_ext) = xt.preamble else { panic!("the extrinsic is signed") };
+
+		let era = Era::decode(&mut &tx_ext.4.encode()[..]).unwrap();
+		assert_eq!(era, Era::mortal(period, 1));
+		assert_eq!(era.birth(1), 1);
+		assert_eq!(tx_ext.5 .0, 0);
+		let (tip, asset_id) =
+			<(Compact<Balance>, Option<AssetId>)>::decode(&mut &tx_ext.7.encode()[..]).unwrap();
+		assert_eq!((tip.0, asset_id), (0, None));
+	}
+}
+
+#[test]
+fn offchain_unsigned_extrinsics_are_valid() {
+	let submitted = submitted_by_offchain_workers(false);
+
+	assert_eq!(submitted.len(), 2);
+	for xt in &submitted {
+		assert!(matches!(xt.preamble, Preamble::Bare(_)));
+	}
+	let calls = submitted.into_iter().map(|xt| xt.function).collect::<Vec<_>>();
+	let call = Call::submit_value_unsigned { block_number: 1, value: 43 };
+	assert!(calls.contains(&RuntimeCall::Template(call)));
+}
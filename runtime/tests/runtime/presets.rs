@@ -0,0 +1,69 @@
+use crate::common::*;
+#[cfg(feature = "governance")]
+use pez_minimal_template_runtime::governance::CouncilCollective;
+use pez_minimal_template_runtime::{
+	genesis_config_presets::{self, STAGING_RUNTIME_PRESET},
+	Balances, Grandpa, Runtime,
+};
+use pezkuwi_sdk::{
+	pezframe_support::traits::fungible::Inspect,
+	pezpallet_aura,
+	pezsp_genesis_builder::{PresetId, DEV_RUNTIME_PRESET, LOCAL_TESTNET_RUNTIME_PRESET},
+	pezsp_keyring::Sr25519Keyring,
+};
+
+#[test]
+fn every_listed_preset_builds() {
+	for id in genesis_config_presets::preset_names() {
+		let id: &str = id.as_ref();
+		assert!(genesis_config_presets::get_preset(&PresetId::from(id)).is_some());
+		new_test_ext_from_preset(id);
+	}
+	assert!(genesis_config_presets::get_preset(&PresetId::from("unknown")).is_none());
+}
+
+#[test]
+fn presets_set_their_own_authorities_root_and_endowments() {
+	let cases = [
+		(DEV_RUNTIME_PRESET, 1, Sr25519Keyring::Alice, Sr25519Keyring::iter().count()),
+		(LOCAL_TESTNET_RUNTIME_PRESET, 2, Sr25519Keyring::Alice, Sr25519Keyring::iter().count()),
+		(STAGING_RUNTIME_PRESET, 3, Sr25519Keyring::Dave, 4),
+	];
+
+	for (id, authorities, root, endowed) in cases {
+		new_test_ext_from_preset(id).execute_with(|| {
+			assert_eq!(pezpallet_aura::Authorities::<Runtime>::get().len(), authorities, "{id}");
+			assert_eq!(Grandpa::grandpa_authorities().len(), authorities, "{id}");
+			#[cfg(not(feature = "governance"))]
+			assert_eq!(
+				pezkuwi_sdk::pezpallet_sudo::Key::<Runtime>::get(),
+				Some(root.to_account_id()),
+				"{id}"
+			);
+			#[cfg(feature = "governance")]
+			assert_eq!(
+				pezkuwi_sdk::pezpallet_collective::Members::<Runtime, CouncilCollective>::get(),
+				vec![root.to_account_id()],
+				"{id}"
+			);
+			let endowed_accounts = Sr25519Keyring::iter()
+				.filter(|a| Balances::balance(&a.to_account_id()) > 0)
+				.count();
+			assert_eq!(endowed_accounts, endowed, "{id}");
+		});
+	}
+}
+
+#[test]
+fn dev_preset_template_value_round_trips_through_build_state() {
+	let preset = genesis_config_presets::get_preset(&PresetId::from(DEV_RUNTIME_PRESET)).unwrap();
+	let patch: serde_json::Value = serde_json::from_slice(&preset).unwrap();
+	assert_eq!(patch["template"]["value"], 42);
+
+	new_test_ext().execute_with(|| {
+		assert_eq!(pezpallet_minimal_template::Value::<Runtime>::get().map(|r| r.value), Some(42));
+	});
+	new_test_ext_from_preset(LOCAL_TESTNET_RUNTIME_PRESET).execute_with(|| {
+		assert_eq!(pezpallet_minimal_template::Value::<Runtime>::get(), None);
+	});
+}
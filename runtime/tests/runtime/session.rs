@@ -0,0 +1,67 @@
+use crate::common::*;
+use codec::Encode;
+use pez_minimal_template_runtime::{interface::Block, opaque::SessionKeys, Runtime};
+use pezkuwi_sdk::{
+	pezkuwi_sdk_frame::runtime::apis::SessionKeys as SessionKeysApi,
+	pezpallet_session,
+	pezsp_consensus_aura::AURA_ENGINE_ID,
+	pezsp_consensus_grandpa::KEY_TYPE as GRANDPA_KEY_TYPE,
+	pezsp_core::crypto::{ByteArray, KeyTypeId},
+	pezsp_keyring::{Ed25519Keyring, Sr25519Keyring},
+	pezsp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt},
+};
+use std::sync::Arc;
+
+fn bob_keys() -> SessionKeys {
+	SessionKeys {
+		aura: Sr25519Keyring::Bob.public().into(),
+		grandpa: Ed25519Keyring::Bob.public().into(),
+	}
+}
+
+#[test]
+fn session_keys_decode_into_aura_and_grandpa_keys() {
+	let raw = SessionKeys::decode_into_raw_public_keys(&bob_keys().encode())
+		.expect("encoded session keys decode");
+
+	assert_eq!(
+		raw,
+		vec![
+			(Sr25519Keyring::Bob.public().to_raw_vec(), KeyTypeId(AURA_ENGINE_ID)),
+			(Ed25519Keyring::Bob.public().to_raw_vec(), GRANDPA_KEY_TYPE),
+		]
+	);
+}
+
+#[test]
+fn generated_session_keys_are_stored_in_the_keystore_and_decode() {
+	let keystore = Arc::new(MemoryKeystore::new());
+	let mut ext = new_test_ext();
+	ext.register_extension(KeystoreExt::new(keystore.clone()));
+
+	let encoded =
+		ext.execute_with(|| <Runtime as SessionKeysApi<Block>>::generate_session_keys(None));
+
+	let aura = keystore.sr25519_public_keys(KeyTypeId(AURA_ENGINE_ID));
+	let grandpa = keystore.ed25519_public_keys(GRANDPA_KEY_TYPE);
+	assert_eq!((aura.len(), grandpa.len()), (1, 1));
+	assert_eq!(
+		<Runtime as SessionKeysApi<Block>>::decode_session_keys(encoded),
+		Some(vec![
+			(aura[0].to_raw_vec(), KeyTypeId(AURA_ENGINE_ID)),
+			(grandpa[0].to_raw_vec(), GRANDPA_KEY_TYPE),
+		])
+	);
+}
+
+#[test]
+fn genesis_authorities_are_session_validators_with_keys() {
+	new_test_ext().execute_with(|| {
+		let alice = Sr25519Keyring::Alice.to_account_id();
+
+		assert_eq!(pezpallet_session::Validators::<Runtime>::get(), vec![alice.clone()]);
+		let keys = pezpallet_session::NextKeys::<Runtime>::get(&alice).expect("alice has keys");
+		assert_eq!(keys.aura, Sr25519Keyring::Alice.public().into());
+		assert_eq!(keys.grandpa, Ed25519Keyring::Alice.public().into());
+	});
+}
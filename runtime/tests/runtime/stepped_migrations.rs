@@ -0,0 +1,42 @@
+use crate::common::*;
+use pez_minimal_template_runtime::{
+	interface::AccountId, MultiBlockMigrations, Runtime, RuntimeExecutive, SLOT_DURATION,
+};
+use pezkuwi_sdk::{
+	pezframe_support::migrations::MultiStepMigrator, pezsp_keyring::Sr25519Keyring,
+	pezsp_runtime::ExtrinsicInclusionMode,
+};
+use pezpallet_minimal_template::StoreCount;
+
+/// More reaped accounts than a single block has the weight to prune.
+const REAPED_ACCOUNTS: u32 = 30_000;
+
+#[test]
+fn transactions_wait_until_stepped_migrations_are_done() {
+	new_test_ext().execute_with(|| {
+		let alice = Sr25519Keyring::Alice.to_account_id();
+		StoreCount::<Runtime>::insert(&alice, 1);
+		for i in 0..REAPED_ACCOUNTS {
+			let mut reaped = [0xff; 32];
+			reaped[..4].copy_from_slice(&i.to_le_bytes());
+			StoreCount::<Runtime>::insert(AccountId::from(reaped), 1);
+		}
+
+		RuntimeExecutive::execute_on_runtime_upgrade();
+		assert!(MultiBlockMigrations::ongoing());
+
+		let mut now = 0;
+		let mut migrating_blocks = 0;
+		while MultiBlockMigrations::ongoing() {
+			now += SLOT_DURATION;
+			assert_eq!(build_block(now), ExtrinsicInclusionMode::OnlyInherents);
+			migrating_blocks += 1;
+			assert!(migrating_blocks < 10, "the migration does not finish");
+		}
+		assert!(migrating_blocks > 1, "the migration fits in a single block");
+
+		now += SLOT_DURATION;
+		assert_eq!(build_block(now), ExtrinsicInclusionMode::AllExtrinsics);
+		assert_eq!(StoreCount::<Runtime>::iter_keys().collect::<Vec<_>>(), vec![alice]);
+	});
+}
@@ -0,0 +1,63 @@
+use crate::common::*;
+use codec::Encode;
+use pez_minimal_template_runtime::{
+	Balances, RuntimeCall, RuntimeHoldReason, RuntimeOrigin, StagingTemplate, Template,
+	TemplateEntryDepositBase, TemplateEntryDepositPerByte,
+};
+use pezkuwi_sdk::{
+	pezframe_support::{
+		assert_ok,
+		traits::{
+			fungible::{Inspect, InspectHold},
+			Get,
+		},
+	},
+	pezsp_keyring::Sr25519Keyring::Alice,
+};
+use pezpallet_minimal_template::HoldReason;
+
+#[test]
+fn staging_template_is_independent_of_template() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(StagingTemplate::get_value(), None);
+
+		assert_ok!(StagingTemplate::set_value(RuntimeOrigin::signed(Alice.to_account_id()), 7));
+
+		assert_eq!(StagingTemplate::get_value(), Some(7));
+		assert_eq!(Template::get_value(), Some(42));
+		assert_eq!(StagingTemplate::get_store_count(Alice.to_account_id()), 1);
+		assert_eq!(Template::get_store_count(Alice.to_account_id()), 0);
+	});
+}
+
+#[test]
+fn template_instances_have_distinct_call_indices() {
+	let template =
+		RuntimeCall::Template(pezpallet_minimal_template::Call::set_value { value: 7 }).encode();
+	let staging =
+		RuntimeCall::StagingTemplate(pezpallet_minimal_template::Call::set_value { value: 7 })
+			.encode();
+
+	assert_eq!(template[0], 5);
+	assert_eq!(staging[0], 20);
+	assert_eq!(template[1..], staging[1..]);
+}
+
+#[test]
+fn entry_deposits_are_held_in_balances_and_released_on_clear() {
+	new_test_ext().execute_with(|| {
+		let alice = Alice.to_account_id();
+		let before = Balances::balance(&alice);
+		let reason = RuntimeHoldReason::Template(HoldReason::EntryDeposit);
+		let deposit = TemplateEntryDepositBase::get() + 10 * TemplateEntryDepositPerByte::get();
+
+		let entry = vec![1; 10].try_into().unwrap();
+		assert_ok!(Template::set_entry(RuntimeOrigin::signed(alice.clone()), entry));
+		assert_eq!(Balances::balance_on_hold(&reason, &alice), deposit);
+		assert_eq!(Balances::balance(&alice), before - deposit);
+
+		assert_ok!(Template::clear_entry(RuntimeOrigin::signed(alice.clone())));
+		assert_eq!(Balances::balance_on_hold(&reason, &alice), 0);
+		assert_eq!(Balances::balance(&alice), before);
+	});
+}